@@ -16,6 +16,7 @@
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "Ad", strict(unknown_attribute, unknown_element))]
 pub struct Ad<'a> {
     /// An ad server-defined identifier string for the ad.
@@ -0,0 +1,145 @@
+use std::borrow::Cow;
+
+impl<'a> crate::Icon<'a> {
+    /// The `program` of the AdChoices industry icon.
+    pub const AD_CHOICES: &'static str = "AdChoices";
+
+    /// Returns a synthetic AdChoices icon of `width` by `height` pixels showing `image`, which
+    /// opens `click_through` when clicked, for responses without one. It is placed at the top,
+    /// right corner.
+    ///
+    /// ```
+    /// let image = vast4::StaticResource {
+    ///     creative_type: "image/png".into(),
+    ///     uri: "https://example.com/adchoices.png".into(),
+    /// };
+    /// let fallback = vast4::Icon::ad_choices(20, 20, image, "https://example.com/privacy");
+    /// let linear = vast4::Linear::default();
+    /// let icon = linear.ad_choices_icon().unwrap_or(&fallback);
+    /// assert!(icon.is_ad_choices());
+    /// ```
+    pub fn ad_choices(
+        width: i32,
+        height: i32,
+        image: crate::StaticResource<'a>,
+        click_through: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            program: Some(Self::AD_CHOICES.into()),
+            width: Some(width),
+            height: Some(height),
+            x_position: Some(crate::XPosition::Right),
+            y_position: Some(crate::YPosition::Top),
+            static_resources: vec![image],
+            icon_clicks: Some(crate::IconClicks {
+                icon_click_through: Some(click_through.into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Returns whether the icon is the AdChoices industry icon.
+    pub fn is_ad_choices(&self) -> bool {
+        self.program.as_deref().is_some_and(|p| p.trim().eq_ignore_ascii_case(Self::AD_CHOICES))
+    }
+
+    /// Returns the `<IconClickFallbackImage>` to display when the icon is clicked on a device
+    /// that can not open a browser, for a screen of `width` by `height` pixels.
+    ///
+    /// The largest image that fits within the screen is chosen, or else the smallest one. Images
+    /// without a `<StaticResource>` are skipped and images without a size are taken as fitting.
+    pub fn click_fallback_image(
+        &self,
+        width: u32,
+        height: u32,
+    ) -> Option<&crate::IconClickFallbackImage<'a>> {
+        let images = self.icon_clicks.as_ref()?.icon_click_fallback_images.as_ref()?;
+        images
+            .icon_click_fallback_images
+            .iter()
+            .filter(|image| image.static_resource.as_deref().is_some_and(|s| !s.trim().is_empty()))
+            // the last maximum is returned, so the first image wins among equals
+            .rev()
+            .max_by_key(|image| {
+                let image_width = i64::from(image.width.unwrap_or_default());
+                let image_height = i64::from(image.height.unwrap_or_default());
+                let fits = image_width <= i64::from(width) && image_height <= i64::from(height);
+                let area = image_width * image_height;
+                (fits, if fits { area } else { -area })
+            })
+    }
+
+    /// Returns the `<IconViewTracking>` URIs to request when the icon is displayed.
+    pub fn view_tracking_uris(&self) -> impl Iterator<Item = &str> {
+        self.icon_view_trackings.iter().map(|uri| uri.trim())
+    }
+
+    /// Returns the `<IconClickTracking>` URIs to request when the icon is clicked.
+    pub fn click_tracking_uris(&self) -> impl Iterator<Item = &str> {
+        self.icon_clicks.iter().flat_map(|c| &c.icon_click_trackings).map(|t| t.uri.trim())
+    }
+}
+
+impl<'a> crate::Icons<'a> {
+    /// Returns the AdChoices industry icon, if any.
+    pub fn ad_choices(&self) -> Option<&crate::Icon<'a>> {
+        self.icons.iter().find(|icon| icon.is_ad_choices())
+    }
+}
+
+impl<'a> crate::Linear<'a> {
+    /// Returns the AdChoices industry icon of the linear creative, if any.
+    pub fn ad_choices_icon(&self) -> Option<&crate::Icon<'a>> {
+        self.icons.as_ref()?.ad_choices()
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_ad_choices() {
+    const ICONS: &str = r#"<Icons>
+      <Icon program="Other" width="10" height="10" />
+      <Icon program="AdChoices" width="20" height="20" xPosition="right" yPosition="top">
+        <StaticResource creativeType="image/png"><![CDATA[https://example.com/adchoices.png]]></StaticResource>
+        <IconClicks>
+          <IconClickFallbackImages>
+            <IconClickFallbackImage width="1920" height="1080"><StaticResource><![CDATA[https://example.com/large.png]]></StaticResource></IconClickFallbackImage>
+            <IconClickFallbackImage width="1280" height="720"><StaticResource><![CDATA[https://example.com/medium.png]]></StaticResource></IconClickFallbackImage>
+            <IconClickFallbackImage width="640" height="360"><StaticResource><![CDATA[https://example.com/small.png]]></StaticResource></IconClickFallbackImage>
+            <IconClickFallbackImage width="320" height="180"><AltText>empty</AltText></IconClickFallbackImage>
+          </IconClickFallbackImages>
+          <IconClickThrough><![CDATA[https://example.com/privacy]]></IconClickThrough>
+          <IconClickTracking><![CDATA[ https://example.com/click ]]></IconClickTracking>
+        </IconClicks>
+        <IconViewTracking><![CDATA[https://example.com/view]]></IconViewTracking>
+      </Icon>
+    </Icons>"#;
+
+    let icons = crate::from_str::<crate::Icons>(ICONS).unwrap();
+    let icon = icons.ad_choices().unwrap();
+    assert_eq!(icon.width, Some(20));
+
+    let image = |width, height| {
+        icon.click_fallback_image(width, height).and_then(|i| i.static_resource.as_deref())
+    };
+    assert_eq!(image(1280, 800), Some("https://example.com/medium.png"));
+    assert_eq!(image(3840, 2160), Some("https://example.com/large.png"));
+    assert_eq!(image(100, 100), Some("https://example.com/small.png"));
+    assert_eq!(icons.icons[0].click_fallback_image(100, 100), None);
+
+    assert_eq!(icon.view_tracking_uris().collect::<Vec<_>>(), ["https://example.com/view"]);
+    assert_eq!(icon.click_tracking_uris().collect::<Vec<_>>(), ["https://example.com/click"]);
+
+    let icons = crate::Icons { icons: vec![icons.icons[0].clone()] };
+    assert_eq!(icons.ad_choices(), None);
+    let image = crate::StaticResource { creative_type: "image/png".into(), uri: "i".into() };
+    let icon = crate::Icon::ad_choices(20, 20, image, "https://example.com/privacy");
+    assert!(icon.is_ad_choices());
+    assert_eq!(
+        icon.layout(640, 360),
+        Some(crate::IconRect { x: 620, y: 0, width: 20, height: 20 })
+    );
+    let xml = crate::to_string(&icon).unwrap();
+    assert_eq!(crate::from_str::<crate::Icon>(&xml).unwrap(), icon);
+}
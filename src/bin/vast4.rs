@@ -0,0 +1,227 @@
+//! Lints, formats and inspects VAST documents.
+
+use std::io::{Read, Write};
+
+use vast4::{AnyVast, Severity};
+
+const USAGE: &str = "\
+Usage: vast4 <COMMAND> [FILE]...
+
+Reads each FILE, or the standard input when there is none or FILE is `-`.
+
+Commands:
+  lint     Reports parse errors and violations of the specification
+  fmt      Writes the document with indentation
+  json     Writes the document as JSON
+  summary  Lists the ads, creatives, media files and tracking of the document
+  urls     Lists every URL of the document with its role
+";
+
+type Result<T, E = Box<dyn std::error::Error>> = std::result::Result<T, E>;
+
+fn main() -> std::process::ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let command: fn(&str, &str, &mut dyn Write) -> Result<bool> =
+        match args.first().map(|s| s.as_str()) {
+            Some("lint") => lint,
+            Some("fmt") => fmt,
+            Some("json") => json,
+            Some("summary") => summary,
+            Some("urls") => urls,
+            Some("-h" | "--help" | "help") => {
+                print!("{USAGE}");
+                return std::process::ExitCode::SUCCESS;
+            }
+            _ => {
+                eprint!("{USAGE}");
+                return std::process::ExitCode::from(2);
+            }
+        };
+
+    let mut files: Vec<&str> = args[1..].iter().map(|s| s.as_str()).collect();
+    if files.is_empty() {
+        files.push("-");
+    }
+
+    let mut ok = true;
+    let mut stdout = std::io::stdout().lock();
+    for file in files {
+        let result = read(file).and_then(|s| command(file, &s, &mut stdout));
+        match result {
+            Ok(passed) => ok &= passed,
+            // the output is no longer read, e.g. when piped to `head`
+            Err(e)
+                if e.downcast_ref::<std::io::Error>()
+                    .is_some_and(|e| e.kind() == std::io::ErrorKind::BrokenPipe) =>
+            {
+                break
+            }
+            Err(e) => {
+                eprintln!("{file}: {e}");
+                ok = false;
+            }
+        }
+    }
+
+    if ok {
+        std::process::ExitCode::SUCCESS
+    } else {
+        std::process::ExitCode::FAILURE
+    }
+}
+
+fn read(file: &str) -> Result<String> {
+    let mut s = String::new();
+    if file == "-" {
+        std::io::stdin().read_to_string(&mut s)?;
+    } else {
+        s = std::fs::read_to_string(file)?;
+    }
+    Ok(s)
+}
+
+fn lint(file: &str, s: &str, out: &mut dyn Write) -> Result<bool> {
+    let vast = match AnyVast::parse(s) {
+        Ok(vast) => vast,
+        Err(e) => {
+            writeln!(
+                out,
+                "{file}:{}:{}: error: {} ({})",
+                e.line(),
+                e.column(),
+                e.xml_error(),
+                e.path()
+            )?;
+            return Ok(false);
+        }
+    };
+
+    // a VAST 3.0 document is checked once converted
+    let mut ok = true;
+    for diagnostic in vast.into_vast().validate() {
+        ok &= diagnostic.severity < Severity::Error;
+        writeln!(out, "{file}: {diagnostic}")?;
+    }
+    Ok(ok)
+}
+
+fn fmt(_: &str, s: &str, out: &mut dyn Write) -> Result<bool> {
+    let options = vast4::WriteOptions { indent: Some(2), ..Default::default() };
+    match AnyVast::parse(s)? {
+        AnyVast::V3(vast) => options.to_writer(&vast, &mut *out)?,
+        AnyVast::V4(vast) => options.to_writer(&vast, &mut *out)?,
+    }
+    writeln!(out)?;
+    Ok(true)
+}
+
+fn json(_: &str, s: &str, out: &mut dyn Write) -> Result<bool> {
+    match AnyVast::parse(s)? {
+        AnyVast::V3(vast) => serde_json::to_writer_pretty(&mut *out, &vast)?,
+        AnyVast::V4(vast) => serde_json::to_writer_pretty(&mut *out, &vast)?,
+    }
+    writeln!(out)?;
+    Ok(true)
+}
+
+fn summary(file: &str, s: &str, out: &mut dyn Write) -> Result<bool> {
+    let any = AnyVast::parse(s)?;
+    let version = any.vast_version().map_or_else(|_| "unknown".to_owned(), |v| v.to_string());
+    let vast = any.into_vast();
+    writeln!(out, "{file}: VAST {version}, {} ad(s)", vast.ads.len())?;
+
+    for (i, ad) in vast.ads.iter().enumerate() {
+        let id = ad.id.as_deref().unwrap_or("-");
+        let creatives = if let Some(ref in_line) = ad.in_line {
+            writeln!(
+                out,
+                "Ad[{i}] id={id} InLine \"{}\" ({})",
+                in_line.ad_title, in_line.ad_system.name
+            )?;
+            writeln!(out, "  impressions: {}", in_line.impressions.len())?;
+            in_line.creatives.creatives.as_slice()
+        } else if let Some(ref wrapper) = ad.wrapper {
+            writeln!(out, "Ad[{i}] id={id} Wrapper {}", wrapper.vast_ad_tag_uri.trim())?;
+            writeln!(out, "  impressions: {}", wrapper.impressions.len())?;
+            wrapper.creatives.as_ref().map_or(&[][..], |c| c.creatives.as_slice())
+        } else {
+            writeln!(out, "Ad[{i}] id={id} empty")?;
+            &[]
+        };
+
+        for (j, creative) in creatives.iter().enumerate() {
+            let id = creative.id.as_deref().unwrap_or("-");
+            if let Some(ref linear) = creative.linear {
+                let duration = linear.duration.as_ref().map_or("-".to_owned(), |d| d.to_string());
+                writeln!(out, "  Creative[{j}] id={id} Linear duration={duration}")?;
+                let media_files = linear.media_files.iter().flat_map(|m| &m.media_files);
+                for media_file in media_files {
+                    let bitrate = media_file.bitrate.map_or("-".to_owned(), |b| format!("{b}kbps"));
+                    writeln!(
+                        out,
+                        "    MediaFile {}x{} {} {bitrate} {} {}",
+                        media_file.width,
+                        media_file.height,
+                        media_file.mime_type,
+                        media_file.delivery,
+                        media_file.uri.trim()
+                    )?;
+                }
+                write_trackings(out, linear.tracking_events.iter().flat_map(|t| &t.trackings))?;
+            }
+            if let Some(ref non_linear_ads) = creative.non_linear_ads {
+                writeln!(
+                    out,
+                    "  Creative[{j}] id={id} NonLinearAds count={}",
+                    non_linear_ads.non_linears.len()
+                )?;
+                write_trackings(
+                    out,
+                    non_linear_ads.tracking_events.iter().flat_map(|t| &t.trackings),
+                )?;
+            }
+            if let Some(ref companion_ads) = creative.companion_ads {
+                let sizes: Vec<_> = companion_ads
+                    .companions
+                    .iter()
+                    .map(|c| format!("{}x{}", c.width, c.height))
+                    .collect();
+                writeln!(out, "  Creative[{j}] id={id} CompanionAds {}", sizes.join(" "))?;
+            }
+        }
+    }
+    Ok(true)
+}
+
+// Writes the number of trackings of each event, in the order the events first appear.
+fn write_trackings<'a, 'b: 'a>(
+    out: &mut dyn Write,
+    trackings: impl Iterator<Item = &'a vast4::Tracking<'b>>,
+) -> Result<()> {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for tracking in trackings {
+        let event = tracking.event.to_string();
+        match counts.iter_mut().find(|(e, _)| *e == event) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((event, 1)),
+        }
+    }
+    if !counts.is_empty() {
+        let counts: Vec<_> =
+            counts.iter().map(|(event, count)| format!("{event}={count}")).collect();
+        writeln!(out, "    tracking: {}", counts.join(" "))?;
+    }
+    Ok(())
+}
+
+fn urls(_: &str, s: &str, out: &mut dyn Write) -> Result<bool> {
+    let vast = AnyVast::parse(s)?.into_vast();
+    let mut result = Ok(());
+    vast.visit_urls(&mut |role: vast4::UrlRole, _: &str, url: &str| {
+        if result.is_ok() {
+            result = writeln!(out, "{role}\t{}", url.trim());
+        }
+    });
+    result?;
+    Ok(true)
+}
@@ -0,0 +1,854 @@
+use std::borrow::Cow;
+
+/// An element required by the specification was not added to a builder.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct BuildError {
+    path: &'static str,
+}
+
+impl BuildError {
+    fn missing(path: &'static str) -> Self {
+        Self { path }
+    }
+
+    /// Returns the path of the missing element, e.g. `InLine/Impression`.
+    pub fn path(&self) -> &'static str {
+        self.path
+    }
+}
+
+impl std::fmt::Display for BuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "missing required element: {}", self.path)
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Builds a [`Vast`](crate::Vast) document.
+///
+/// ```
+/// # fn main() -> Result<(), vast4::BuildError> {
+/// use vast4::{
+///     AdBuilder, CreativeBuilder, DeliveryProtocol, Duration, InLineBuilder, LinearBuilder,
+///     MediaFileBuilder, VastBuilder, VastVersion,
+/// };
+///
+/// let linear = LinearBuilder::new(Duration::new(0, 0, 15, 0))
+///     .media_file(
+///         MediaFileBuilder::new(
+///             "https://example.com/video.mp4",
+///             DeliveryProtocol::Progressive,
+///             "video/mp4",
+///             1280,
+///             720,
+///         )
+///         .build(),
+///     )
+///     .build()?;
+/// let in_line = InLineBuilder::new("iabtechlab", "Ad Title", "a532d16d")
+///     .impression("https://example.com/impression")
+///     .creative(CreativeBuilder::linear(linear).universal_ad_id("Ad-ID", "8465").build())
+///     .build()?;
+/// let vast =
+///     VastBuilder::new(VastVersion::V4_2).ad(AdBuilder::in_line(in_line).id("1").build()).build();
+/// assert!(vast.validate().is_empty());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct VastBuilder<'a> {
+    vast: crate::Vast<'a>,
+}
+
+impl<'a> VastBuilder<'a> {
+    pub fn new(version: crate::VastVersion) -> Self {
+        Self { vast: crate::Vast { version: version.to_string().into(), ..Default::default() } }
+    }
+
+    pub fn ad(mut self, ad: crate::Ad<'a>) -> Self {
+        self.vast.ads.push(ad);
+        self
+    }
+
+    /// Adds an `<Error>` URI, to be requested when no ad is returned.
+    pub fn error(mut self, uri: impl Into<Cow<'a, str>>) -> Self {
+        self.vast.errors.push(uri.into());
+        self
+    }
+
+    pub fn build(self) -> crate::Vast<'a> {
+        self.vast
+    }
+}
+
+/// Builds an [`Ad`](crate::Ad), containing either an [`InLine`](crate::InLine) or a
+/// [`Wrapper`](crate::Wrapper).
+#[derive(Clone, Debug)]
+pub struct AdBuilder<'a> {
+    ad: crate::Ad<'a>,
+}
+
+impl<'a> AdBuilder<'a> {
+    pub fn in_line(in_line: crate::InLine<'a>) -> Self {
+        Self { ad: crate::Ad { in_line: Some(in_line), ..Default::default() } }
+    }
+
+    pub fn wrapper(wrapper: crate::Wrapper<'a>) -> Self {
+        Self { ad: crate::Ad { wrapper: Some(wrapper), ..Default::default() } }
+    }
+
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.ad.id = Some(id.into());
+        self
+    }
+
+    pub fn sequence(mut self, sequence: i32) -> Self {
+        self.ad.sequence = Some(sequence);
+        self
+    }
+
+    pub fn conditional_ad(mut self, conditional_ad: bool) -> Self {
+        self.ad.conditional_ad = Some(conditional_ad);
+        self
+    }
+
+    pub fn ad_type(mut self, ad_type: crate::AdType) -> Self {
+        self.ad.ad_type = Some(ad_type.to_string().into());
+        self
+    }
+
+    pub fn build(self) -> crate::Ad<'a> {
+        self.ad
+    }
+}
+
+/// Builds an [`InLine`](crate::InLine).
+///
+/// The ad system, title and serving id are required by [`InLineBuilder::new`], and
+/// [`build`](InLineBuilder::build) fails without an impression or a creative, with a creative
+/// without a universal ad id, or with a linear without a duration or a media file, such as one
+/// started with [`LinearBuilder::wrapper`].
+#[derive(Clone, Debug)]
+pub struct InLineBuilder<'a> {
+    in_line: crate::InLine<'a>,
+}
+
+impl<'a> InLineBuilder<'a> {
+    pub fn new(
+        ad_system: impl Into<Cow<'a, str>>,
+        ad_title: impl Into<Cow<'a, str>>,
+        ad_serving_id: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            in_line: crate::InLine {
+                ad_system: crate::AdSystem { version: None, name: ad_system.into() },
+                ad_title: ad_title.into(),
+                ad_serving_id: Some(ad_serving_id.into()),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn ad_system_version(mut self, version: impl Into<Cow<'a, str>>) -> Self {
+        self.in_line.ad_system.version = Some(version.into());
+        self
+    }
+
+    pub fn impression(mut self, uri: impl Into<Cow<'a, str>>) -> Self {
+        self.in_line.impressions.push(crate::Impression { id: None, uri: uri.into() });
+        self
+    }
+
+    pub fn error(mut self, uri: impl Into<Cow<'a, str>>) -> Self {
+        self.in_line.errors.push(uri.into());
+        self
+    }
+
+    pub fn creative(mut self, creative: crate::Creative<'a>) -> Self {
+        self.in_line.creatives.creatives.push(creative);
+        self
+    }
+
+    pub fn pricing(
+        mut self,
+        model: crate::PriceModel,
+        currency: impl Into<Cow<'a, str>>,
+        price: f64,
+    ) -> Self {
+        self.in_line.pricing = Some(crate::Pricing { model, currency: currency.into(), price });
+        self
+    }
+
+    pub fn advertiser(mut self, advertiser: impl Into<Cow<'a, str>>) -> Self {
+        self.in_line.advertiser = Some(advertiser.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.in_line.description = Some(description.into());
+        self
+    }
+
+    pub fn category(
+        mut self,
+        authority: impl Into<Cow<'a, str>>,
+        code: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        self.in_line
+            .categories
+            .push(crate::Category { authority: authority.into(), code: code.into() });
+        self
+    }
+
+    /// Sets the number of seconds the ad may be cached for.
+    pub fn expires(mut self, expires: i32) -> Self {
+        self.in_line.expires = Some(expires);
+        self
+    }
+
+    pub fn viewable_impression(
+        mut self,
+        viewable_impression: crate::ViewableImpression<'a>,
+    ) -> Self {
+        self.in_line.viewable_impression = Some(viewable_impression);
+        self
+    }
+
+    pub fn verification(mut self, verification: crate::Verification<'a>) -> Self {
+        self.in_line
+            .ad_verifications
+            .get_or_insert_with(Default::default)
+            .verifications
+            .push(verification);
+        self
+    }
+
+    pub fn extension(mut self, extension: crate::Extension) -> Self {
+        self.in_line.extensions.get_or_insert_with(Default::default).extensions.push(extension);
+        self
+    }
+
+    pub fn build(self) -> Result<crate::InLine<'a>, BuildError> {
+        if self.in_line.impressions.is_empty() {
+            return Err(BuildError::missing("InLine/Impression"));
+        }
+        if self.in_line.creatives.creatives.is_empty() {
+            return Err(BuildError::missing("InLine/Creatives/Creative"));
+        }
+        if self.in_line.creatives.creatives.iter().any(|c| c.universal_ad_id.is_empty()) {
+            return Err(BuildError::missing("InLine/Creatives/Creative/UniversalAdId"));
+        }
+        for linear in self.in_line.creatives.creatives.iter().filter_map(|c| c.linear.as_ref()) {
+            if linear.duration.is_none() {
+                return Err(BuildError::missing("InLine/Creatives/Creative/Linear/Duration"));
+            }
+            if linear.media_files.as_ref().is_none_or(|m| m.media_files.is_empty()) {
+                return Err(BuildError::missing(
+                    "InLine/Creatives/Creative/Linear/MediaFiles/MediaFile",
+                ));
+            }
+        }
+        Ok(self.in_line)
+    }
+}
+
+/// Builds a [`Wrapper`](crate::Wrapper).
+///
+/// The ad system and the `<VASTAdTagURI>` are required by [`WrapperBuilder::new`], and
+/// [`build`](WrapperBuilder::build) fails without an impression.
+#[derive(Clone, Debug)]
+pub struct WrapperBuilder<'a> {
+    wrapper: crate::Wrapper<'a>,
+}
+
+impl<'a> WrapperBuilder<'a> {
+    pub fn new(
+        ad_system: impl Into<Cow<'a, str>>,
+        vast_ad_tag_uri: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            wrapper: crate::Wrapper {
+                ad_system: crate::AdSystem { version: None, name: ad_system.into() },
+                vast_ad_tag_uri: vast_ad_tag_uri.into(),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn ad_system_version(mut self, version: impl Into<Cow<'a, str>>) -> Self {
+        self.wrapper.ad_system.version = Some(version.into());
+        self
+    }
+
+    pub fn follow_additional_wrappers(mut self, follow_additional_wrappers: bool) -> Self {
+        self.wrapper.follow_additional_wrappers = Some(follow_additional_wrappers);
+        self
+    }
+
+    pub fn allow_multiple_ads(mut self, allow_multiple_ads: bool) -> Self {
+        self.wrapper.allow_multiple_ads = Some(allow_multiple_ads);
+        self
+    }
+
+    pub fn fallback_on_no_ad(mut self, fallback_on_no_ad: bool) -> Self {
+        self.wrapper.fallback_on_no_ad = Some(fallback_on_no_ad);
+        self
+    }
+
+    pub fn impression(mut self, uri: impl Into<Cow<'a, str>>) -> Self {
+        self.wrapper.impressions.push(crate::Impression { id: None, uri: uri.into() });
+        self
+    }
+
+    pub fn error(mut self, uri: impl Into<Cow<'a, str>>) -> Self {
+        self.wrapper.errors.push(uri.into());
+        self
+    }
+
+    /// Adds a creative, carrying the tracking of the wrapper for the creative of the wrapped ad.
+    pub fn creative(mut self, creative: crate::Creative<'a>) -> Self {
+        self.wrapper.creatives.get_or_insert_with(Default::default).creatives.push(creative);
+        self
+    }
+
+    pub fn pricing(
+        mut self,
+        model: crate::PriceModel,
+        currency: impl Into<Cow<'a, str>>,
+        price: f64,
+    ) -> Self {
+        self.wrapper.pricing = Some(crate::Pricing { model, currency: currency.into(), price });
+        self
+    }
+
+    pub fn blocked_ad_categories(
+        mut self,
+        authority: Option<Cow<'a, str>>,
+        codes: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        self.wrapper
+            .blocked_ad_categories
+            .push(crate::BlockedAdCategories { authority, codes: codes.into() });
+        self
+    }
+
+    pub fn viewable_impression(
+        mut self,
+        viewable_impression: crate::ViewableImpression<'a>,
+    ) -> Self {
+        self.wrapper.viewable_impression = Some(viewable_impression);
+        self
+    }
+
+    pub fn verification(mut self, verification: crate::Verification<'a>) -> Self {
+        self.wrapper
+            .ad_verifications
+            .get_or_insert_with(Default::default)
+            .verifications
+            .push(verification);
+        self
+    }
+
+    pub fn extension(mut self, extension: crate::Extension) -> Self {
+        self.wrapper.extensions.get_or_insert_with(Default::default).extensions.push(extension);
+        self
+    }
+
+    pub fn build(self) -> Result<crate::Wrapper<'a>, BuildError> {
+        if self.wrapper.impressions.is_empty() {
+            return Err(BuildError::missing("Wrapper/Impression"));
+        }
+        Ok(self.wrapper)
+    }
+}
+
+/// Builds a [`Creative`](crate::Creative) from its [`Linear`](crate::Linear),
+/// [`NonLinearAds`](crate::NonLinearAds) or [`CompanionAds`](crate::CompanionAds).
+#[derive(Clone, Debug)]
+pub struct CreativeBuilder<'a> {
+    creative: crate::Creative<'a>,
+}
+
+impl<'a> CreativeBuilder<'a> {
+    pub fn linear(linear: crate::Linear<'a>) -> Self {
+        Self { creative: crate::Creative { linear: Some(linear), ..Default::default() } }
+    }
+
+    pub fn non_linear_ads(non_linear_ads: crate::NonLinearAds<'a>) -> Self {
+        Self {
+            creative: crate::Creative {
+                non_linear_ads: Some(non_linear_ads),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Starts a creative of companions, added with [`companion`](CreativeBuilder::companion).
+    pub fn companion_ads(required: Option<crate::CompanionRequirement>) -> Self {
+        Self {
+            creative: crate::Creative {
+                companion_ads: Some(crate::CompanionAds { required, companions: Vec::new() }),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Adds a companion, starting the `<CompanionAds>` of the creative if needed.
+    pub fn companion(mut self, companion: crate::Companion<'a>) -> Self {
+        self.creative.companion_ads.get_or_insert_with(Default::default).companions.push(companion);
+        self
+    }
+
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.creative.id = Some(id.into());
+        self
+    }
+
+    pub fn ad_id(mut self, ad_id: impl Into<Cow<'a, str>>) -> Self {
+        self.creative.ad_id = Some(ad_id.into());
+        self
+    }
+
+    pub fn sequence(mut self, sequence: i32) -> Self {
+        self.creative.sequence = Some(sequence);
+        self
+    }
+
+    pub fn api_framework(mut self, api_framework: impl Into<Cow<'a, str>>) -> Self {
+        self.creative.api_framework = Some(api_framework.into());
+        self
+    }
+
+    /// Adds a `<UniversalAdId>`, required for the creatives of an [`InLine`](crate::InLine)
+    /// since VAST 4.1.
+    pub fn universal_ad_id(
+        mut self,
+        id_registry: impl Into<Cow<'a, str>>,
+        id: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        self.creative
+            .universal_ad_id
+            .push(crate::UniversalAdId { id_registry: id_registry.into(), id: id.into() });
+        self
+    }
+
+    pub fn creative_extension(mut self, creative_extension: crate::CreativeExtension) -> Self {
+        self.creative
+            .creative_extensions
+            .get_or_insert_with(Default::default)
+            .creative_extensions
+            .push(creative_extension);
+        self
+    }
+
+    pub fn build(self) -> crate::Creative<'a> {
+        self.creative
+    }
+}
+
+/// Builds a [`Linear`](crate::Linear).
+///
+/// The linear of an [`InLine`](crate::InLine) is started with [`LinearBuilder::new`], and
+/// [`build`](LinearBuilder::build) fails without a media file. The linear of a
+/// [`Wrapper`](crate::Wrapper) is started with [`LinearBuilder::wrapper`].
+#[derive(Clone, Debug)]
+pub struct LinearBuilder<'a> {
+    linear: crate::Linear<'a>,
+    in_line: bool,
+}
+
+impl<'a> LinearBuilder<'a> {
+    pub fn new(duration: crate::Duration) -> Self {
+        Self {
+            linear: crate::Linear { duration: Some(duration), ..Default::default() },
+            in_line: true,
+        }
+    }
+
+    /// Starts a linear with tracking and clicks only, as found in a [`Wrapper`](crate::Wrapper).
+    pub fn wrapper() -> Self {
+        Self { linear: Default::default(), in_line: false }
+    }
+
+    pub fn skipoffset(mut self, skipoffset: crate::Duration) -> Self {
+        self.linear.skipoffset = Some(skipoffset);
+        self
+    }
+
+    pub fn media_file(mut self, media_file: crate::MediaFile<'a>) -> Self {
+        self.media_files().media_files.push(media_file);
+        self
+    }
+
+    pub fn mezzanine(mut self, mezzanine: crate::Mezzanine<'a>) -> Self {
+        self.media_files().mezzanines.push(mezzanine);
+        self
+    }
+
+    pub fn interactive_creative_file(
+        mut self,
+        interactive_creative_file: crate::InteractiveCreativeFile<'a>,
+    ) -> Self {
+        self.media_files().interactive_creative_files.push(interactive_creative_file);
+        self
+    }
+
+    pub fn closed_caption_file(
+        mut self,
+        closed_caption_file: crate::ClosedCaptionFile<'a>,
+    ) -> Self {
+        self.media_files()
+            .closed_caption_files
+            .get_or_insert_with(Default::default)
+            .closed_caption_files
+            .push(closed_caption_file);
+        self
+    }
+
+    fn media_files(&mut self) -> &mut crate::MediaFiles<'a> {
+        self.linear.media_files.get_or_insert_with(Default::default)
+    }
+
+    pub fn tracking(mut self, event: crate::TrackingEvent, uri: impl Into<Cow<'a, str>>) -> Self {
+        self.trackings().push(crate::Tracking { event, offset: None, uri: uri.into() });
+        self
+    }
+
+    /// Adds a `progress` tracking, requested when the playback reaches `offset`.
+    pub fn progress(mut self, offset: crate::Offset, uri: impl Into<Cow<'a, str>>) -> Self {
+        self.trackings().push(crate::Tracking {
+            event: crate::TrackingEvent::Progress,
+            offset: Some(offset),
+            uri: uri.into(),
+        });
+        self
+    }
+
+    fn trackings(&mut self) -> &mut Vec<crate::Tracking<'a>> {
+        &mut self.linear.tracking_events.get_or_insert_with(Default::default).trackings
+    }
+
+    pub fn click_through(mut self, uri: impl Into<Cow<'a, str>>) -> Self {
+        self.video_clicks().click_through = Some(crate::ClickThrough { id: None, uri: uri.into() });
+        self
+    }
+
+    pub fn click_tracking(mut self, uri: impl Into<Cow<'a, str>>) -> Self {
+        self.video_clicks()
+            .click_trackings
+            .push(crate::ClickTracking { id: None, uri: uri.into() });
+        self
+    }
+
+    pub fn custom_click(mut self, uri: impl Into<Cow<'a, str>>) -> Self {
+        self.video_clicks().custom_clicks.push(crate::CustomClick { id: None, uri: uri.into() });
+        self
+    }
+
+    fn video_clicks(&mut self) -> &mut crate::VideoClicks<'a> {
+        self.linear.video_clicks.get_or_insert_with(Default::default)
+    }
+
+    pub fn icon(mut self, icon: crate::Icon<'a>) -> Self {
+        self.linear.icons.get_or_insert_with(Default::default).icons.push(icon);
+        self
+    }
+
+    pub fn ad_parameters(mut self, ad_parameters: crate::AdParameters<'a>) -> Self {
+        self.linear.ad_parameters = Some(ad_parameters);
+        self
+    }
+
+    pub fn build(self) -> Result<crate::Linear<'a>, BuildError> {
+        let media_files = self.linear.media_files.as_ref();
+        if self.in_line && media_files.is_none_or(|m| m.media_files.is_empty()) {
+            return Err(BuildError::missing("Linear/MediaFiles/MediaFile"));
+        }
+        Ok(self.linear)
+    }
+}
+
+/// Builds a [`MediaFile`](crate::MediaFile), with its required attributes given to
+/// [`MediaFileBuilder::new`].
+#[derive(Clone, Debug)]
+pub struct MediaFileBuilder<'a> {
+    media_file: crate::MediaFile<'a>,
+}
+
+impl<'a> MediaFileBuilder<'a> {
+    pub fn new(
+        uri: impl Into<Cow<'a, str>>,
+        delivery: crate::DeliveryProtocol,
+        mime_type: impl Into<Cow<'a, str>>,
+        width: i32,
+        height: i32,
+    ) -> Self {
+        Self {
+            media_file: crate::MediaFile {
+                uri: uri.into(),
+                delivery,
+                mime_type: mime_type.into(),
+                width,
+                height,
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.media_file.id = Some(id.into());
+        self
+    }
+
+    pub fn codec(mut self, codec: impl Into<Cow<'a, str>>) -> Self {
+        self.media_file.codec = Some(codec.into());
+        self
+    }
+
+    /// Sets the average bitrate, in Kbps.
+    pub fn bitrate(mut self, bitrate: i32) -> Self {
+        self.media_file.bitrate = Some(bitrate);
+        self
+    }
+
+    /// Sets the bitrate range of a streaming media file, in Kbps.
+    pub fn bitrate_range(mut self, min_bitrate: i32, max_bitrate: i32) -> Self {
+        self.media_file.min_bitrate = Some(min_bitrate);
+        self.media_file.max_bitrate = Some(max_bitrate);
+        self
+    }
+
+    pub fn scalable(mut self, scalable: bool) -> Self {
+        self.media_file.scalable = Some(scalable);
+        self
+    }
+
+    pub fn maintain_aspect_ratio(mut self, maintain_aspect_ratio: bool) -> Self {
+        self.media_file.maintain_aspect_ratio = Some(maintain_aspect_ratio);
+        self
+    }
+
+    pub fn file_size(mut self, file_size: i32) -> Self {
+        self.media_file.file_size = Some(file_size);
+        self
+    }
+
+    pub fn media_type(mut self, media_type: impl Into<Cow<'a, str>>) -> Self {
+        self.media_file.media_type = Some(media_type.into());
+        self
+    }
+
+    pub fn api_framework(mut self, api_framework: impl Into<Cow<'a, str>>) -> Self {
+        self.media_file.api_framework = Some(api_framework.into());
+        self
+    }
+
+    pub fn build(self) -> crate::MediaFile<'a> {
+        self.media_file
+    }
+}
+
+/// Builds a [`Companion`](crate::Companion).
+///
+/// The size is required by [`CompanionBuilder::new`], and [`build`](CompanionBuilder::build)
+/// fails without a resource.
+#[derive(Clone, Debug)]
+pub struct CompanionBuilder<'a> {
+    companion: crate::Companion<'a>,
+}
+
+impl<'a> CompanionBuilder<'a> {
+    pub fn new(width: i32, height: i32) -> Self {
+        Self { companion: crate::Companion { width, height, ..Default::default() } }
+    }
+
+    pub fn id(mut self, id: impl Into<Cow<'a, str>>) -> Self {
+        self.companion.id = Some(id.into());
+        self
+    }
+
+    pub fn asset_size(mut self, asset_width: i32, asset_height: i32) -> Self {
+        self.companion.asset_width = Some(asset_width);
+        self.companion.asset_height = Some(asset_height);
+        self
+    }
+
+    pub fn expanded_size(mut self, expanded_width: i32, expanded_height: i32) -> Self {
+        self.companion.expanded_width = Some(expanded_width);
+        self.companion.expanded_height = Some(expanded_height);
+        self
+    }
+
+    pub fn api_framework(mut self, api_framework: impl Into<Cow<'a, str>>) -> Self {
+        self.companion.api_framework = Some(api_framework.into());
+        self
+    }
+
+    pub fn ad_slot_id(mut self, ad_slot_id: impl Into<Cow<'a, str>>) -> Self {
+        self.companion.ad_slot_id = Some(ad_slot_id.into());
+        self
+    }
+
+    pub fn pxratio(mut self, pxratio: f32) -> Self {
+        self.companion.pxratio = Some(pxratio);
+        self
+    }
+
+    pub fn rendering_mode(mut self, rendering_mode: crate::RenderingMode) -> Self {
+        self.companion.rendering_mode = Some(rendering_mode);
+        self
+    }
+
+    pub fn static_resource(
+        mut self,
+        creative_type: impl Into<Cow<'a, str>>,
+        uri: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        self.companion
+            .static_resources
+            .push(crate::StaticResource { creative_type: creative_type.into(), uri: uri.into() });
+        self
+    }
+
+    pub fn html_resource(mut self, html: impl Into<Cow<'a, str>>) -> Self {
+        self.companion.html_resources.push(html.into());
+        self
+    }
+
+    pub fn iframe_resource(mut self, uri: impl Into<Cow<'a, str>>) -> Self {
+        self.companion.iframe_resources.push(uri.into());
+        self
+    }
+
+    pub fn ad_parameters(mut self, ad_parameters: crate::AdParameters<'a>) -> Self {
+        self.companion.ad_parameters = Some(ad_parameters);
+        self
+    }
+
+    pub fn alt_text(mut self, alt_text: impl Into<Cow<'a, str>>) -> Self {
+        self.companion.alt_text = Some(alt_text.into());
+        self
+    }
+
+    pub fn click_through(mut self, uri: impl Into<Cow<'a, str>>) -> Self {
+        self.companion.companion_click_through = Some(uri.into());
+        self
+    }
+
+    pub fn click_tracking(
+        mut self,
+        id: impl Into<Cow<'a, str>>,
+        uri: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        self.companion
+            .companion_click_trackings
+            .push(crate::CompanionClickTracking { id: Some(id.into()), uri: uri.into() });
+        self
+    }
+
+    /// Adds a `creativeView` tracking, the only event tracked for companions.
+    pub fn creative_view(mut self, uri: impl Into<Cow<'a, str>>) -> Self {
+        self.companion.tracking_events.get_or_insert_with(Default::default).trackings.push(
+            crate::Tracking {
+                event: crate::TrackingEvent::CreativeView,
+                offset: None,
+                uri: uri.into(),
+            },
+        );
+        self
+    }
+
+    pub fn build(self) -> Result<crate::Companion<'a>, BuildError> {
+        let companion = &self.companion;
+        if companion.static_resources.is_empty()
+            && companion.html_resources.is_empty()
+            && companion.iframe_resources.is_empty()
+        {
+            return Err(BuildError::missing("Companion/StaticResource"));
+        }
+        Ok(self.companion)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_builder() {
+    use crate::{DeliveryProtocol, Duration, TrackingEvent, VastVersion};
+
+    let media_file = MediaFileBuilder::new(
+        "https://example.com/video.mp4",
+        DeliveryProtocol::Progressive,
+        "video/mp4",
+        1280,
+        720,
+    )
+    .codec("H.264")
+    .bitrate(2000)
+    .build();
+    let linear = LinearBuilder::new(Duration::new(0, 0, 16, 0))
+        .tracking(TrackingEvent::Start, "https://example.com/start")
+        .media_file(media_file)
+        .click_through("https://example.com")
+        .build()
+        .unwrap();
+    let companion = CompanionBuilder::new(300, 250)
+        .static_resource("image/png", "https://example.com/companion.png")
+        .creative_view("https://example.com/creative-view")
+        .build()
+        .unwrap();
+    let in_line = InLineBuilder::new("iabtechlab", "Inline Simple Ad", "a532d16d")
+        .ad_system_version("1")
+        .impression("https://example.com/impression")
+        .error("https://example.com/error")
+        .creative(
+            CreativeBuilder::linear(linear.clone())
+                .id("5480")
+                .universal_ad_id("Ad-ID", "8465")
+                .build(),
+        )
+        .creative(
+            CreativeBuilder::companion_ads(None)
+                .companion(companion)
+                .universal_ad_id("Ad-ID", "8466")
+                .build(),
+        )
+        .build()
+        .unwrap();
+    let vast = VastBuilder::new(VastVersion::V4_2)
+        .ad(AdBuilder::in_line(in_line).id("20001").build())
+        .build();
+    assert_eq!(vast.validate(), []);
+
+    let xml = crate::to_string(&vast).unwrap();
+    assert_eq!(crate::from_str::<crate::Vast>(&xml).unwrap(), vast);
+    assert!(xml.contains(r#"<MediaFiles><MediaFile delivery="progressive" type="video/mp4""#));
+
+    let error = InLineBuilder::new("iabtechlab", "title", "id")
+        .impression("https://example.com/impression")
+        .build()
+        .unwrap_err();
+    assert_eq!(error.path(), "InLine/Creatives/Creative");
+    assert_eq!(error.to_string(), "missing required element: InLine/Creatives/Creative");
+    let error = InLineBuilder::new("iabtechlab", "title", "id")
+        .impression("https://example.com/impression")
+        .creative(CreativeBuilder::linear(linear.clone()).build())
+        .build()
+        .unwrap_err();
+    assert_eq!(error.path(), "InLine/Creatives/Creative/UniversalAdId");
+    // the linear of a wrapper has neither duration nor media files
+    let error = InLineBuilder::new("iabtechlab", "title", "id")
+        .impression("https://example.com/impression")
+        .creative(
+            CreativeBuilder::linear(LinearBuilder::wrapper().build().unwrap())
+                .universal_ad_id("Ad-ID", "8465")
+                .build(),
+        )
+        .build()
+        .unwrap_err();
+    assert_eq!(error.path(), "InLine/Creatives/Creative/Linear/Duration");
+    assert!(LinearBuilder::new(Duration::new(0, 0, 16, 0)).build().is_err());
+    assert!(LinearBuilder::wrapper().build().is_ok());
+    assert!(WrapperBuilder::new("iabtechlab", "https://example.com/vast").build().is_err());
+    assert!(CompanionBuilder::new(300, 250).build().is_err());
+}
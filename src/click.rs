@@ -12,6 +12,7 @@
 /// </xs:complexType>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "VideoClicks", strict(unknown_attribute, unknown_element))]
 pub struct VideoClicks<'a> {
     /// The container for zero or more [`<ClickTracking>`](ClickTracking) elements.
@@ -40,6 +41,7 @@ pub struct VideoClicks<'a> {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "ClickThrough", strict(unknown_attribute, unknown_element))]
 pub struct ClickThrough<'a> {
     /// A unique ID for the clickthrough.
@@ -66,6 +68,7 @@ pub struct ClickThrough<'a> {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "ClickTracking", strict(unknown_attribute, unknown_element))]
 pub struct ClickTracking<'a> {
     /// A unique ID for the click to be tracked.
@@ -92,6 +95,7 @@ pub struct ClickTracking<'a> {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "CustomClick", strict(unknown_attribute, unknown_element))]
 pub struct CustomClick<'a> {
     /// A unique ID for the custom click to be tracked.
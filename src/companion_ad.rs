@@ -10,6 +10,7 @@
 /// </xs:complexType>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "CompanionAds", strict(unknown_attribute, unknown_element))]
 pub struct CompanionAds<'a> {
     /// How the player should treat a companion ad when multiple are supplied.
@@ -101,6 +102,7 @@ impl std::fmt::Display for CompanionRequirement {
 /// </xs:complexType>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "Companion", strict(unknown_attribute, unknown_element))]
 pub struct Companion<'a> {
     /// An optional identifier for the creative.
@@ -234,11 +236,14 @@ impl std::fmt::Display for RenderingMode {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "CompanionClickTracking", strict(unknown_attribute, unknown_element))]
 pub struct CompanionClickTracking<'a> {
-    /// An id provided by the ad server to track the click in reports.
-    #[xml(attr = "id")]
-    pub id: std::borrow::Cow<'a, str>,
+    /// An id provided by the ad server to track the click in reports. It is `None` for a
+    /// companion converted from VAST 3.0. VAST 4.x requires it, but a missing attribute is
+    /// reported by [`validate`](crate::validate) rather than when parsing.
+    #[xml(attr = "id", default)]
+    pub id: Option<std::borrow::Cow<'a, str>>,
 
     /// A URI to a tracking resource file used to track a companion clickthrough.
     #[xml(text, cdata)]
@@ -0,0 +1,218 @@
+/// A slot on the publisher's page a [`Companion`](crate::Companion) can be displayed in.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct CompanionSlot {
+    /// The identifier of the slot, matched against
+    /// [`Companion::ad_slot_id`](crate::Companion::ad_slot_id). A companion intended for another
+    /// slot is not displayed in this one.
+    pub id: Option<String>,
+    /// The width of the slot, in pixels.
+    pub width: u32,
+    /// The height of the slot, in pixels.
+    pub height: u32,
+    /// The kinds of resources the slot can display. Empty means any kind can be displayed.
+    pub resource_kinds: Vec<crate::ResourceKind>,
+    /// The pixel ratio of the device. A companion intended for another ratio is not displayed.
+    /// `None` means any ratio is displayed.
+    pub pxratio: Option<f32>,
+}
+
+/// A [`Companion`](crate::Companion) and the slot it is displayed in.
+#[derive(PartialEq, Clone, Debug)]
+pub struct CompanionPlacement<'b, 'a> {
+    pub companion: &'b crate::Companion<'a>,
+    /// The index of the slot in the slots given to
+    /// [`CompanionAds::select`](crate::CompanionAds::select).
+    pub slot: usize,
+}
+
+/// The result of [`CompanionAds::select`](crate::CompanionAds::select).
+#[derive(PartialEq, Clone, Debug)]
+pub struct CompanionSelection<'b, 'a> {
+    /// The companions displayed along with the ad, i.e. those whose
+    /// [`RenderingMode`](crate::RenderingMode) is `Default` or `Concurrent`.
+    pub concurrent: Vec<CompanionPlacement<'b, 'a>>,
+    /// The companions displayed once the ad has ended, i.e. those whose
+    /// [`RenderingMode`](crate::RenderingMode) is `EndCard`. They may use the same slots as the
+    /// concurrent companions.
+    pub end_cards: Vec<CompanionPlacement<'b, 'a>>,
+    /// The companions that fit none of the remaining slots, in document order.
+    pub unplaced: Vec<&'b crate::Companion<'a>>,
+}
+
+/// Represents an error for selecting companions: the companions required by
+/// [`CompanionAds::required`](crate::CompanionAds::required) can not be displayed.
+#[derive(PartialEq, Clone, Debug)]
+pub struct CompanionSelectError<'b, 'a> {
+    /// The requirement that is not met.
+    pub required: crate::CompanionRequirement,
+    /// The companions that fit none of the remaining slots, in document order.
+    pub unplaced: Vec<&'b crate::Companion<'a>>,
+}
+
+impl CompanionSelectError<'_, '_> {
+    /// Returns the error code the media player should report for this error.
+    pub fn code(&self) -> crate::VastErrorCode {
+        crate::VastErrorCode::RequiredCompanion
+    }
+}
+
+impl std::fmt::Display for CompanionSelectError<'_, '_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.required {
+            crate::CompanionRequirement::All => {
+                write!(f, "{} required companions can not be displayed", self.unplaced.len())
+            }
+            _ => write!(f, "none of the {} companions can be displayed", self.unplaced.len()),
+        }
+    }
+}
+
+impl std::error::Error for CompanionSelectError<'_, '_> {}
+
+impl<'a> crate::CompanionAds<'a> {
+    /// Selects the slot each [`Companion`](crate::Companion) is displayed in.
+    ///
+    /// A companion fits a slot when its display size of `width` by `height` pixels fits within
+    /// the slot, the slot can display one of its resources, and its `adSlotId` and `pxratio`, if
+    /// any, match the slot. The `assetWidth` and `assetHeight` of a high density creative are
+    /// device pixels and not matched against the slot. Each companion, in
+    /// document order, takes the smallest remaining slot it fits, preferring the slot with its
+    /// `adSlotId`. The concurrent companions and the end cards are placed separately.
+    ///
+    /// When `required` is `all`, every companion must be placed, and when it is `any`, at least
+    /// one. Otherwise an error with the code
+    /// [`RequiredCompanion`](crate::VastErrorCode::RequiredCompanion) (602) is returned.
+    pub fn select<'b>(
+        &'b self,
+        slots: &[CompanionSlot],
+    ) -> Result<CompanionSelection<'b, 'a>, CompanionSelectError<'b, 'a>> {
+        let mut unplaced = Vec::new();
+        let end_card = |(_, c): &(usize, &crate::Companion)| {
+            c.rendering_mode == Some(crate::RenderingMode::EndCard)
+        };
+        let companions = self.companions.iter().enumerate();
+        let concurrent = place(companions.clone().filter(|c| !end_card(c)), slots, &mut unplaced);
+        let end_cards = place(companions.filter(end_card), slots, &mut unplaced);
+        // keep the document order across both kinds
+        unplaced.sort_unstable();
+        let unplaced: Vec<_> = unplaced.into_iter().map(|i| &self.companions[i]).collect();
+
+        let required = self.required.unwrap_or(crate::CompanionRequirement::None);
+        let placed = concurrent.len() + end_cards.len();
+        let met = match required {
+            crate::CompanionRequirement::All => unplaced.is_empty(),
+            crate::CompanionRequirement::Any => placed > 0 || self.companions.is_empty(),
+            crate::CompanionRequirement::None => true,
+        };
+        match met {
+            true => Ok(CompanionSelection { concurrent, end_cards, unplaced }),
+            false => Err(CompanionSelectError { required, unplaced }),
+        }
+    }
+}
+
+fn place<'b, 'a>(
+    companions: impl Iterator<Item = (usize, &'b crate::Companion<'a>)>,
+    slots: &[CompanionSlot],
+    unplaced: &mut Vec<usize>,
+) -> Vec<CompanionPlacement<'b, 'a>> {
+    let mut used = vec![false; slots.len()];
+    let mut placements = Vec::new();
+    for (index, companion) in companions {
+        let slot =
+            (0..slots.len()).filter(|&i| !used[i] && fits(companion, &slots[i])).min_by_key(|&i| {
+                let slot = &slots[i];
+                let named =
+                    slot.id.is_some() && slot.id.as_deref() == companion.ad_slot_id.as_deref();
+                (!named, u64::from(slot.width) * u64::from(slot.height))
+            });
+        match slot {
+            Some(slot) => {
+                used[slot] = true;
+                placements.push(CompanionPlacement { companion, slot });
+            }
+            None => unplaced.push(index),
+        }
+    }
+    placements
+}
+
+fn fits(companion: &crate::Companion, slot: &CompanionSlot) -> bool {
+    if let (Some(id), Some(slot_id)) = (&companion.ad_slot_id, &slot.id) {
+        if id != slot_id {
+            return false;
+        }
+    }
+
+    let (width, height) = (i64::from(companion.width), i64::from(companion.height));
+    if width > i64::from(slot.width) || height > i64::from(slot.height) {
+        return false;
+    }
+
+    let kinds = match slot.resource_kinds.is_empty() {
+        true => &crate::ResourceKind::ALL[..],
+        false => &slot.resource_kinds[..],
+    };
+    if companion.resource(kinds).is_none() {
+        return false;
+    }
+
+    match slot.pxratio {
+        Some(pxratio) => (companion.pxratio.unwrap_or(1.0) - pxratio).abs() < f32::EPSILON,
+        None => true,
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_select_companions() {
+    const COMPANION_ADS: &str = r#"<CompanionAds required="all">
+      <Companion id="banner" width="300" height="250"><StaticResource creativeType="image/png"><![CDATA[banner]]></StaticResource></Companion>
+      <Companion id="named" width="300" height="250" adSlotId="side"><HTMLResource><![CDATA[<p>named</p>]]></HTMLResource></Companion>
+      <Companion id="retina" width="300" height="250" assetWidth="600" assetHeight="500" pxratio="2"><StaticResource creativeType="image/png"><![CDATA[retina]]></StaticResource></Companion>
+      <Companion id="end" width="640" height="360" renderingMode="end-card"><IFrameResource><![CDATA[end]]></IFrameResource></Companion>
+    </CompanionAds>"#;
+
+    fn ids(placements: &[CompanionPlacement]) -> Vec<(String, usize)> {
+        placements
+            .iter()
+            .map(|p| (p.companion.id.as_deref().unwrap_or_default().to_owned(), p.slot))
+            .collect()
+    }
+
+    let mut companion_ads = crate::from_str::<crate::CompanionAds>(COMPANION_ADS).unwrap();
+    let mut slots = vec![
+        CompanionSlot { width: 728, height: 600, ..Default::default() },
+        CompanionSlot { width: 300, height: 250, ..Default::default() },
+        CompanionSlot { id: Some("side".into()), width: 300, height: 600, ..Default::default() },
+        CompanionSlot { width: 320, height: 250, ..Default::default() },
+    ];
+
+    let selection = companion_ads.select(&slots).unwrap();
+    assert_eq!(
+        ids(&selection.concurrent),
+        [("banner".into(), 1), ("named".into(), 2), ("retina".into(), 3)]
+    );
+    assert_eq!(ids(&selection.end_cards), [("end".into(), 0)]);
+    assert!(selection.unplaced.is_empty());
+
+    slots[0].pxratio = Some(1.0);
+    slots[0].resource_kinds = vec![crate::ResourceKind::Static];
+    slots[3].pxratio = Some(1.0);
+    let error = companion_ads.select(&slots).unwrap_err();
+    assert_eq!(
+        error.unplaced.iter().map(|c| c.id.as_deref().unwrap()).collect::<Vec<_>>(),
+        ["retina", "end"]
+    );
+    assert_eq!(error.code(), crate::VastErrorCode::RequiredCompanion);
+
+    companion_ads.required = Some(crate::CompanionRequirement::Any);
+    let selection = companion_ads.select(&slots).unwrap();
+    assert_eq!(selection.unplaced.len(), 2);
+    assert!(companion_ads.select(&[]).is_err());
+
+    companion_ads.required = None;
+    let selection = companion_ads.select(&[]).unwrap();
+    assert!(selection.concurrent.is_empty() && selection.end_cards.is_empty());
+    assert_eq!(selection.unplaced.len(), 4);
+}
@@ -11,6 +11,7 @@
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "Creatives", strict(unknown_attribute, unknown_element))]
 pub struct Creatives<'a> {
     /// The container for one or more [`<Creative>`](Creative) elements.
@@ -57,6 +58,7 @@ pub struct Creatives<'a> {
 /// </xs:complexType>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "Creative", strict(unknown_attribute, unknown_element))]
 pub struct Creative<'a> {
     /// A number representing the numerical order in which each sequenced creative within
@@ -110,6 +112,7 @@ pub struct Creative<'a> {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "UniversalAdId", strict(unknown_attribute, unknown_element))]
 pub struct UniversalAdId<'a> {
     /// A string used to identify the URL for the registry website where the unique
@@ -133,6 +136,7 @@ pub struct UniversalAdId<'a> {
 /// </xs:complexType>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "CreativeExtensions", strict(unknown_attribute, unknown_element))]
 pub struct CreativeExtensions {
     /// The container for zero or more [`<CreativeExtension>`](CreativeExtension) elements.
@@ -155,6 +159,7 @@ pub struct CreativeExtensions {
 /// </xs:element>
 /// ```
 #[derive(Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CreativeExtension {
     /// The MIME type of any code that might be included in the extension.
     pub mime_type: Option<String>,
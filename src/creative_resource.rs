@@ -13,6 +13,7 @@
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "StaticResource", strict(unknown_attribute, unknown_element))]
 pub struct StaticResource<'a> {
     /// Identifies the MIME type of the creative provided.
@@ -24,3 +25,147 @@ pub struct StaticResource<'a> {
     #[xml(text, cdata, default)]
     pub uri: std::borrow::Cow<'a, str>,
 }
+
+/// The kind of a resource of a [`Companion`](crate::Companion), [`NonLinear`](crate::NonLinear)
+/// or [`Icon`](crate::Icon).
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum ResourceKind {
+    /// A [`<StaticResource>`](StaticResource), e.g. an image.
+    Static,
+    /// An `<IFrameResource>`, the URI of a page loaded in an iframe.
+    IFrame,
+    /// An `<HTMLResource>`, an HTML snippet.
+    Html,
+}
+
+impl StaticResource<'_> {
+    /// Returns the typed MIME type of the creative.
+    pub fn mime(&self) -> crate::MimeType {
+        self.creative_type.parse().unwrap_or_else(|e| match e {})
+    }
+}
+
+impl ResourceKind {
+    /// Every kind, in the order renderers usually prefer them: static, iframe, then HTML.
+    pub const ALL: [Self; 3] = [Self::Static, Self::IFrame, Self::Html];
+}
+
+/// A resource of a [`Companion`](crate::Companion), [`NonLinear`](crate::NonLinear) or
+/// [`Icon`](crate::Icon), whichever element it is given by.
+#[derive(PartialEq, Clone, Debug)]
+pub enum CreativeResource<'b> {
+    /// A [`<StaticResource>`](StaticResource).
+    Static {
+        /// The typed `creativeType` of the resource.
+        creative_type: crate::MimeType,
+        /// The URI of the creative file.
+        uri: &'b str,
+    },
+    /// An `<IFrameResource>`, with the URI of the page.
+    IFrame(&'b str),
+    /// An `<HTMLResource>`, with the HTML snippet.
+    Html(&'b str),
+}
+
+impl CreativeResource<'_> {
+    /// Returns the kind of the resource.
+    pub fn kind(&self) -> ResourceKind {
+        match self {
+            Self::Static { .. } => ResourceKind::Static,
+            Self::IFrame(_) => ResourceKind::IFrame,
+            Self::Html(_) => ResourceKind::Html,
+        }
+    }
+}
+
+// Yields the resources of the kinds of `preference`, in that order and then in document order.
+fn creative_resources<'b>(
+    static_resources: &'b [StaticResource],
+    iframe_resources: &'b [std::borrow::Cow<str>],
+    html_resources: &'b [std::borrow::Cow<str>],
+    preference: &[ResourceKind],
+) -> impl Iterator<Item = CreativeResource<'b>> {
+    let preference = preference.to_vec();
+    preference.into_iter().flat_map(move |kind| -> Box<dyn Iterator<Item = _>> {
+        match kind {
+            ResourceKind::Static => {
+                Box::new(static_resources.iter().map(|r| CreativeResource::Static {
+                    creative_type: r.mime(),
+                    uri: r.uri.trim(),
+                }))
+            }
+            ResourceKind::IFrame => {
+                Box::new(iframe_resources.iter().map(|uri| CreativeResource::IFrame(uri.trim())))
+            }
+            ResourceKind::Html => {
+                Box::new(html_resources.iter().map(|html| CreativeResource::Html(html)))
+            }
+        }
+    })
+}
+
+macro_rules! impl_creative_resources {
+    ($($ty:ident),*) => {
+        $(
+            impl crate::$ty<'_> {
+                /// Returns the resources a renderer supporting the kinds of `preference` can
+                /// display, the preferred kind first, e.g. [`ResourceKind::ALL`].
+                pub fn resources(
+                    &self,
+                    preference: &[ResourceKind],
+                ) -> impl Iterator<Item = CreativeResource<'_>> {
+                    creative_resources(
+                        &self.static_resources,
+                        &self.iframe_resources,
+                        &self.html_resources,
+                        preference,
+                    )
+                }
+
+                /// Returns the resource a renderer supporting the kinds of `preference` displays,
+                /// i.e. the first of [`resources`](Self::resources).
+                pub fn resource(&self, preference: &[ResourceKind]) -> Option<CreativeResource<'_>> {
+                    self.resources(preference).next()
+                }
+            }
+        )*
+    };
+}
+
+impl_creative_resources!(Companion, NonLinear, Icon);
+
+#[cfg(test)]
+#[test]
+fn test_creative_resources() {
+    let companion = crate::from_str::<crate::Companion>(
+        r#"<Companion width="300" height="250">
+          <HTMLResource><![CDATA[<p>html</p>]]></HTMLResource>
+          <IFrameResource><![CDATA[ https://example.com/iframe ]]></IFrameResource>
+          <StaticResource creativeType="image/png"><![CDATA[https://example.com/1.png]]></StaticResource>
+          <StaticResource creativeType="image/gif"><![CDATA[https://example.com/2.gif]]></StaticResource>
+        </Companion>"#,
+    )
+    .unwrap();
+
+    let resources = companion.resources(&ResourceKind::ALL).collect::<Vec<_>>();
+    assert_eq!(
+        resources,
+        [
+            CreativeResource::Static {
+                creative_type: crate::MimeType::Png,
+                uri: "https://example.com/1.png"
+            },
+            CreativeResource::Static {
+                creative_type: crate::MimeType::Gif,
+                uri: "https://example.com/2.gif"
+            },
+            CreativeResource::IFrame("https://example.com/iframe"),
+            CreativeResource::Html("<p>html</p>"),
+        ]
+    );
+
+    let preference = [ResourceKind::Html, ResourceKind::IFrame];
+    assert_eq!(companion.resource(&preference), Some(CreativeResource::Html("<p>html</p>")));
+    assert_eq!(companion.resources(&[ResourceKind::IFrame]).count(), 1);
+    assert_eq!(crate::Icon::default().resource(&ResourceKind::ALL), None);
+}
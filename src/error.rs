@@ -17,3 +17,652 @@ impl std::fmt::Display for VastParseError {
 }
 
 impl std::error::Error for VastParseError {}
+
+/// A `Result` whose error is an [`Error`].
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Represents an error for reading a document, with the place in the document it happened.
+#[derive(Debug)]
+pub struct Error {
+    inner: hard_xml::XmlError,
+    line: usize,
+    column: usize,
+    path: String,
+}
+
+impl Error {
+    /// Locates `inner`, returned by `parse` for `s`, in `s`.
+    ///
+    /// `parse` is retried on prefixes of `s` ending on a token boundary: the shortest prefix
+    /// `parse` fails on with the same error ends with the offending token.
+    pub(crate) fn locate<'a>(
+        s: &'a str,
+        inner: hard_xml::XmlError,
+        parse: impl Fn(&'a str) -> hard_xml::XmlResult<()>,
+    ) -> Self {
+        use hard_xml::xmlparser::Tokenizer;
+
+        let mut tokens = Vec::new();
+        for token in Tokenizer::from(s) {
+            match token {
+                Ok(token) => tokens.push(token),
+                Err(_) => break,
+            }
+        }
+
+        if let hard_xml::XmlError::Parser(ref e) = inner {
+            let pos = e.pos();
+            return Self {
+                path: element_path(&tokens, tokens.len()),
+                line: pos.row as usize,
+                column: pos.col as usize,
+                inner,
+            };
+        }
+
+        let message = inner.to_string();
+        let (mut lo, mut hi) = (0, tokens.len());
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            match parse(&s[..token_span(&tokens[mid]).end()]) {
+                Err(e) if e.to_string() == message => hi = mid,
+                _ => lo = mid + 1,
+            }
+        }
+
+        // the offending token, or the end of the document
+        let offset = tokens.get(lo).map_or(s.len(), |t| token_span(t).start());
+        let (line, column) = position(s, offset);
+        Self { path: element_path(&tokens, lo), line, column, inner }
+    }
+
+    /// Moves the error located in `from` to `to`, e.g. from a rewritten document to its source,
+    /// with `map` converting an offset in `from` into one in `to`.
+    pub(crate) fn relocate(
+        mut self,
+        from: &str,
+        to: &str,
+        map: impl FnOnce(usize) -> usize,
+    ) -> Self {
+        let start = from.split_inclusive('\n').take(self.line - 1).map(str::len).sum::<usize>();
+        let line = &from[start..];
+        let offset =
+            start + line.char_indices().nth(self.column - 1).map_or(line.len(), |(i, _)| i);
+        (self.line, self.column) = position(to, map(offset));
+        self
+    }
+
+    /// Wraps an error reading the document itself, which has no place in it.
+    pub(crate) fn io(e: std::io::Error) -> Self {
+        Self { inner: hard_xml::XmlError::IO(e), line: 1, column: 1, path: String::new() }
+    }
+
+    /// Returns the line the error happened on, starting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column the error happened on, starting from 1.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the path of the element or attribute the error happened on, e.g.
+    /// `VAST/Ad[2]/InLine/Creatives/Creative[0]/Linear/TrackingEvents/Tracking[3]@event`.
+    ///
+    /// The elements that may occur more than once are followed by their index among the
+    /// siblings of the same name.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the error of the XML reader.
+    pub fn xml_error(&self) -> &hard_xml::XmlError {
+        &self.inner
+    }
+
+    /// Returns the error of parsing a value, e.g. an unknown tracking event, if any.
+    pub fn parse_error(&self) -> Option<&VastParseError> {
+        match &self.inner {
+            hard_xml::XmlError::FromStr(e) => e.downcast_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the error code the media player should report for this error: a schema
+    /// validation error if the document is well-formed XML, an XML parsing error otherwise.
+    pub fn code(&self) -> VastErrorCode {
+        use hard_xml::XmlError::*;
+
+        match self.inner {
+            MissingField { .. } | UnknownField { .. } | FromStr(_) => {
+                VastErrorCode::SchemaValidation
+            }
+            _ => VastErrorCode::XmlParsing,
+        }
+    }
+
+    /// Converts the error into the error of the XML reader.
+    pub fn into_inner(self) -> hard_xml::XmlError {
+        self.inner
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at {}:{} ({})", self.inner, self.line, self.column, self.path)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.inner)
+    }
+}
+
+impl From<Error> for hard_xml::XmlError {
+    fn from(value: Error) -> Self {
+        value.inner
+    }
+}
+
+// The elements that may occur more than once in their parent.
+const REPEATED: &[&str] = &[
+    "Ad",
+    "Error",
+    "Impression",
+    "Category",
+    "BlockedAdCategories",
+    "Verification",
+    "ExecutableResource",
+    "JavaScriptResource",
+    "Tracking",
+    "Extension",
+    "Creative",
+    "UniversalAdId",
+    "CreativeExtension",
+    "MediaFile",
+    "Mezzanine",
+    "InteractiveCreativeFile",
+    "ClosedCaptionFile",
+    "ClickTracking",
+    "CustomClick",
+    "Icon",
+    "HTMLResource",
+    "IFrameResource",
+    "StaticResource",
+    "IconViewTracking",
+    "IconClickTracking",
+    "IconClickFallbackImage",
+    "NonLinear",
+    "NonLinearClickTracking",
+    "Companion",
+    "CompanionClickTracking",
+    "Viewable",
+    "NotViewable",
+    "ViewUndetermined",
+    "vmap:AdBreak",
+    "vmap:Tracking",
+    "vmap:Extension",
+];
+
+// Returns the line and column of `offset` in `s`, starting from 1.
+pub(crate) fn position(s: &str, offset: usize) -> (usize, usize) {
+    let before = &s[..offset];
+    let column = before.rsplit('\n').next().unwrap_or_default().chars().count() + 1;
+    (before.matches('\n').count() + 1, column)
+}
+
+// Returns the path of the element, or attribute, the token at `index` belongs to.
+fn element_path(tokens: &[hard_xml::xmlparser::Token], index: usize) -> String {
+    use hard_xml::xmlparser::{ElementEnd, Token};
+
+    // the open elements, with the number of children of each name
+    let mut stack: Vec<(String, std::collections::HashMap<&str, usize>)> = Vec::new();
+    let mut attribute = None;
+    for (i, token) in tokens.iter().enumerate().take(index + 1) {
+        match *token {
+            Token::ElementStart { span, .. } => {
+                let name = &span.as_str()[1..];
+                let segment = match stack.last_mut() {
+                    Some((_, counts)) if REPEATED.contains(&name) => {
+                        let count = counts.entry(name).or_default();
+                        *count += 1;
+                        format!("{name}[{}]", *count - 1)
+                    }
+                    _ => name.to_owned(),
+                };
+                stack.push((segment, Default::default()));
+            }
+            Token::Attribute { span, .. } if i == index => {
+                attribute = span.as_str().split('=').next().map(str::trim);
+            }
+            // the element is kept when the error is found on its end tag
+            Token::ElementEnd { end: ElementEnd::Empty | ElementEnd::Close(..), .. }
+                if i != index =>
+            {
+                stack.pop();
+            }
+            _ => {}
+        }
+    }
+
+    let mut path = stack.into_iter().map(|(segment, _)| segment).collect::<Vec<_>>().join("/");
+    if let Some(attribute) = attribute {
+        path.push('@');
+        path.push_str(attribute);
+    }
+    path
+}
+
+pub(crate) fn token_span<'a>(
+    token: &hard_xml::xmlparser::Token<'a>,
+) -> hard_xml::xmlparser::StrSpan<'a> {
+    use hard_xml::xmlparser::Token;
+
+    match *token {
+        Token::Declaration { span, .. }
+        | Token::ProcessingInstruction { span, .. }
+        | Token::Comment { span, .. }
+        | Token::DtdStart { span, .. }
+        | Token::EmptyDtd { span, .. }
+        | Token::EntityDeclaration { span, .. }
+        | Token::DtdEnd { span }
+        | Token::ElementStart { span, .. }
+        | Token::Attribute { span, .. }
+        | Token::ElementEnd { span, .. }
+        | Token::Cdata { span, .. } => span,
+        Token::Text { text } => text,
+    }
+}
+
+/// The error codes the media player reports using the `[ERRORCODE]` macro (Sec 2.3.6.3).
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum VastErrorCode {
+    /// 100: XML parsing error.
+    XmlParsing,
+    /// 101: VAST schema validation error.
+    SchemaValidation,
+    /// 102: VAST version of response not supported.
+    VersionNotSupported,
+    /// 200: Trafficking error. Media player received an Ad type that it was not expecting and/or
+    /// cannot play.
+    Trafficking,
+    /// 201: Media player expecting different linearity.
+    UnexpectedLinearity,
+    /// 202: Media player expecting different duration.
+    UnexpectedDuration,
+    /// 203: Media player expecting different size.
+    UnexpectedSize,
+    /// 204: Ad category was required but not provided.
+    CategoryRequired,
+    /// 205: Inline Category violates Wrapper BlockedAdCategories.
+    CategoryBlocked,
+    /// 206: Ad Break shortened. Ad was not served.
+    AdBreakShortened,
+    /// 300: General Wrapper error.
+    Wrapper,
+    /// 301: Timeout of VAST URI provided in Wrapper element, or of VAST URI provided in a
+    /// subsequent Wrapper element.
+    WrapperTimeout,
+    /// 302: Wrapper limit reached, as defined by the media player.
+    WrapperLimit,
+    /// 303: No VAST response after one or more Wrappers.
+    NoAdsAfterWrapper,
+    /// 304: InLine response returned ad unit that failed to result in ad display within defined
+    /// time limit.
+    InLineTimeout,
+    /// 400: General Linear error. Media player is unable to display the Linear Ad.
+    Linear,
+    /// 401: File not found. Unable to find Linear/MediaFile from URI.
+    MediaFileNotFound,
+    /// 402: Timeout of MediaFile URI.
+    MediaFileTimeout,
+    /// 403: Couldn’t find MediaFile that is supported by this media player, based on the
+    /// attributes of the MediaFile element.
+    NoSupportedMediaFile,
+    /// 405: Problem displaying MediaFile.
+    MediaFileDisplay,
+    /// 406: Mezzanine was required but not provided. Ad not served.
+    MezzanineRequired,
+    /// 407: Mezzanine is in the process of being downloaded for the first time.
+    MezzanineDownloading,
+    /// 408: Conditional ad rejected.
+    ConditionalAdRejected,
+    /// 409: Interactive unit in the InteractiveCreativeFile node was not executed.
+    InteractiveUnitNotExecuted,
+    /// 410: Verification unit in the Verification node was not executed.
+    VerificationNotExecuted,
+    /// 411: Mezzanine was provided as required, but file did not meet required specification.
+    MezzanineInvalid,
+    /// 500: General NonLinearAds error.
+    NonLinear,
+    /// 501: Unable to display NonLinearAd because creative dimensions do not align with creative
+    /// display area.
+    NonLinearDimensions,
+    /// 502: Unable to fetch NonLinearAds/NonLinear resource.
+    NonLinearFetch,
+    /// 503: Couldn’t find NonLinear resource with supported type.
+    NoSupportedNonLinearResource,
+    /// 600: General CompanionAds error.
+    Companion,
+    /// 601: Unable to display Companion because creative dimensions do not fit within Companion
+    /// display area.
+    CompanionDimensions,
+    /// 602: Unable to display required Companion.
+    RequiredCompanion,
+    /// 603: Unable to fetch CompanionAds/Companion resource.
+    CompanionFetch,
+    /// 604: Couldn’t find Companion resource with supported type.
+    NoSupportedCompanionResource,
+    /// 900: Undefined Error.
+    Undefined,
+    /// 901: General VPAID error.
+    Vpaid,
+    /// 902: General InteractiveCreativeFile error code.
+    InteractiveCreativeFile,
+}
+
+/// The group a [`VastErrorCode`] belongs to.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum VastErrorCategory {
+    /// 1xx: XML and schema errors.
+    Xml,
+    /// 2xx: Trafficking errors.
+    Trafficking,
+    /// 3xx: Wrapper errors.
+    Wrapper,
+    /// 4xx: Linear errors.
+    Linear,
+    /// 500 - 503: NonLinear errors.
+    NonLinear,
+    /// 600 - 604: Companion errors.
+    Companion,
+    /// 410: Verification errors.
+    Verification,
+    /// 409, 901, 902: Interactive creative errors.
+    Interactive,
+    /// 900: Undefined errors.
+    Undefined,
+}
+
+impl VastErrorCode {
+    /// All error codes, in ascending order.
+    pub const ALL: [Self; 38] = [
+        Self::XmlParsing,
+        Self::SchemaValidation,
+        Self::VersionNotSupported,
+        Self::Trafficking,
+        Self::UnexpectedLinearity,
+        Self::UnexpectedDuration,
+        Self::UnexpectedSize,
+        Self::CategoryRequired,
+        Self::CategoryBlocked,
+        Self::AdBreakShortened,
+        Self::Wrapper,
+        Self::WrapperTimeout,
+        Self::WrapperLimit,
+        Self::NoAdsAfterWrapper,
+        Self::InLineTimeout,
+        Self::Linear,
+        Self::MediaFileNotFound,
+        Self::MediaFileTimeout,
+        Self::NoSupportedMediaFile,
+        Self::MediaFileDisplay,
+        Self::MezzanineRequired,
+        Self::MezzanineDownloading,
+        Self::ConditionalAdRejected,
+        Self::InteractiveUnitNotExecuted,
+        Self::VerificationNotExecuted,
+        Self::MezzanineInvalid,
+        Self::NonLinear,
+        Self::NonLinearDimensions,
+        Self::NonLinearFetch,
+        Self::NoSupportedNonLinearResource,
+        Self::Companion,
+        Self::CompanionDimensions,
+        Self::RequiredCompanion,
+        Self::CompanionFetch,
+        Self::NoSupportedCompanionResource,
+        Self::Undefined,
+        Self::Vpaid,
+        Self::InteractiveCreativeFile,
+    ];
+
+    /// Returns the numeric value of the error code.
+    pub fn code(self) -> u16 {
+        match self {
+            Self::XmlParsing => 100,
+            Self::SchemaValidation => 101,
+            Self::VersionNotSupported => 102,
+            Self::Trafficking => 200,
+            Self::UnexpectedLinearity => 201,
+            Self::UnexpectedDuration => 202,
+            Self::UnexpectedSize => 203,
+            Self::CategoryRequired => 204,
+            Self::CategoryBlocked => 205,
+            Self::AdBreakShortened => 206,
+            Self::Wrapper => 300,
+            Self::WrapperTimeout => 301,
+            Self::WrapperLimit => 302,
+            Self::NoAdsAfterWrapper => 303,
+            Self::InLineTimeout => 304,
+            Self::Linear => 400,
+            Self::MediaFileNotFound => 401,
+            Self::MediaFileTimeout => 402,
+            Self::NoSupportedMediaFile => 403,
+            Self::MediaFileDisplay => 405,
+            Self::MezzanineRequired => 406,
+            Self::MezzanineDownloading => 407,
+            Self::ConditionalAdRejected => 408,
+            Self::InteractiveUnitNotExecuted => 409,
+            Self::VerificationNotExecuted => 410,
+            Self::MezzanineInvalid => 411,
+            Self::NonLinear => 500,
+            Self::NonLinearDimensions => 501,
+            Self::NonLinearFetch => 502,
+            Self::NoSupportedNonLinearResource => 503,
+            Self::Companion => 600,
+            Self::CompanionDimensions => 601,
+            Self::RequiredCompanion => 602,
+            Self::CompanionFetch => 603,
+            Self::NoSupportedCompanionResource => 604,
+            Self::Undefined => 900,
+            Self::Vpaid => 901,
+            Self::InteractiveCreativeFile => 902,
+        }
+    }
+
+    /// Returns the error code for the numeric value, if it is defined by the specification.
+    pub fn from_code(code: u16) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.code() == code)
+    }
+
+    /// Returns the description of the error code given by the specification.
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::XmlParsing => "XML parsing error.",
+            Self::SchemaValidation => "VAST schema validation error.",
+            Self::VersionNotSupported => "VAST version of response not supported.",
+            Self::Trafficking => {
+                "Trafficking error. Media player received an Ad type that it was not expecting \
+                 and/or cannot play."
+            }
+            Self::UnexpectedLinearity => "Media player expecting different linearity.",
+            Self::UnexpectedDuration => "Media player expecting different duration.",
+            Self::UnexpectedSize => "Media player expecting different size.",
+            Self::CategoryRequired => "Ad category was required but not provided.",
+            Self::CategoryBlocked => "Inline Category violates Wrapper BlockedAdCategories.",
+            Self::AdBreakShortened => "Ad Break shortened. Ad was not served.",
+            Self::Wrapper => "General Wrapper error.",
+            Self::WrapperTimeout => {
+                "Timeout of VAST URI provided in Wrapper element, or of VAST URI provided in a \
+                 subsequent Wrapper element."
+            }
+            Self::WrapperLimit => {
+                "Wrapper limit reached, as defined by the media player. Too many Wrapper \
+                 responses have been received with no InLine response."
+            }
+            Self::NoAdsAfterWrapper => "No VAST response after one or more Wrappers.",
+            Self::InLineTimeout => {
+                "InLine response returned ad unit that failed to result in ad display within \
+                 defined time limit."
+            }
+            Self::Linear => {
+                "General Linear error. Media player is unable to display the Linear Ad."
+            }
+            Self::MediaFileNotFound => "File not found. Unable to find Linear/MediaFile from URI.",
+            Self::MediaFileTimeout => "Timeout of MediaFile URI.",
+            Self::NoSupportedMediaFile => {
+                "Couldn’t find MediaFile that is supported by this media player, based on the \
+                 attributes of the MediaFile element."
+            }
+            Self::MediaFileDisplay => {
+                "Problem displaying MediaFile. Media player found a MediaFile with supported \
+                 type but couldn’t display it."
+            }
+            Self::MezzanineRequired => "Mezzanine was required but not provided. Ad not served.",
+            Self::MezzanineDownloading => {
+                "Mezzanine is in the process of being downloaded for the first time. Ad will not \
+                 be served until mezzanine is downloaded and transcoded."
+            }
+            Self::ConditionalAdRejected => "Conditional ad rejected.",
+            Self::InteractiveUnitNotExecuted => {
+                "Interactive unit in the InteractiveCreativeFile node was not executed."
+            }
+            Self::VerificationNotExecuted => {
+                "Verification unit in the Verification node was not executed."
+            }
+            Self::MezzanineInvalid => {
+                "Mezzanine was provided as required, but file did not meet required \
+                 specification. Ad not served."
+            }
+            Self::NonLinear => "General NonLinearAds error.",
+            Self::NonLinearDimensions => {
+                "Unable to display NonLinearAd because creative dimensions do not align with \
+                 creative display area."
+            }
+            Self::NonLinearFetch => "Unable to fetch NonLinearAds/NonLinear resource.",
+            Self::NoSupportedNonLinearResource => {
+                "Couldn’t find NonLinear resource with supported type."
+            }
+            Self::Companion => "General CompanionAds error.",
+            Self::CompanionDimensions => {
+                "Unable to display Companion because creative dimensions do not fit within \
+                 Companion display area."
+            }
+            Self::RequiredCompanion => "Unable to display required Companion.",
+            Self::CompanionFetch => "Unable to fetch CompanionAds/Companion resource.",
+            Self::NoSupportedCompanionResource => {
+                "Couldn’t find Companion resource with supported type."
+            }
+            Self::Undefined => "Undefined Error.",
+            Self::Vpaid => "General VPAID error.",
+            Self::InteractiveCreativeFile => "General InteractiveCreativeFile error code.",
+        }
+    }
+
+    /// Returns the group the error code belongs to.
+    pub fn category(self) -> VastErrorCategory {
+        match self {
+            Self::VerificationNotExecuted => VastErrorCategory::Verification,
+            Self::InteractiveUnitNotExecuted | Self::Vpaid | Self::InteractiveCreativeFile => {
+                VastErrorCategory::Interactive
+            }
+            _ => match self.code() / 100 {
+                1 => VastErrorCategory::Xml,
+                2 => VastErrorCategory::Trafficking,
+                3 => VastErrorCategory::Wrapper,
+                4 => VastErrorCategory::Linear,
+                5 => VastErrorCategory::NonLinear,
+                6 => VastErrorCategory::Companion,
+                _ => VastErrorCategory::Undefined,
+            },
+        }
+    }
+}
+
+impl std::str::FromStr for VastErrorCode {
+    type Err = VastParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<u16>()
+            .ok()
+            .and_then(Self::from_code)
+            .ok_or_else(|| VastParseError::new(format!("error code parsing error: '{s}'")))
+    }
+}
+
+impl std::fmt::Display for VastErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_vast_error_code() {
+    for code in VastErrorCode::ALL {
+        assert_eq!(code.to_string().parse::<VastErrorCode>().unwrap(), code);
+    }
+    assert_eq!("303".parse::<VastErrorCode>().unwrap(), VastErrorCode::NoAdsAfterWrapper);
+    assert_eq!(VastErrorCode::WrapperTimeout.category(), VastErrorCategory::Wrapper);
+    assert_eq!(VastErrorCode::VerificationNotExecuted.category(), VastErrorCategory::Verification);
+    assert!("404".parse::<VastErrorCode>().is_err());
+    assert!("abc".parse::<VastErrorCode>().is_err());
+}
+
+#[cfg(test)]
+#[test]
+fn test_error_location() {
+    const XML: &str = r#"<VAST version="4.2">
+  <Ad id="1"/>
+  <Ad id="2"/>
+  <Ad id="3">
+    <InLine>
+      <AdSystem>iabtechlab</AdSystem>
+      <AdTitle>title</AdTitle>
+      <Impression><![CDATA[https://example.com/impression]]></Impression>
+      <AdServingId>id</AdServingId>
+      <Creatives>
+        <Creative>
+          <Linear>
+            <Duration>00:00:16</Duration>
+            <TrackingEvents>
+              <Tracking event="start"><![CDATA[https://example.com/start]]></Tracking>
+              <Tracking event="firstQuartile"><![CDATA[https://example.com/q1]]></Tracking>
+              <Tracking event="midpoint"><![CDATA[https://example.com/mid]]></Tracking>
+              <Tracking event="foo"><![CDATA[https://example.com/foo]]></Tracking>
+            </TrackingEvents>
+          </Linear>
+        </Creative>
+      </Creatives>
+    </InLine>
+  </Ad>
+</VAST>"#;
+
+    let error = crate::from_str::<crate::Vast>(XML).unwrap_err();
+    assert_eq!(
+        error.path(),
+        "VAST/Ad[2]/InLine/Creatives/Creative[0]/Linear/TrackingEvents/Tracking[3]@event"
+    );
+    assert_eq!((error.line(), error.column()), (18, 25));
+    assert_eq!(
+        error.parse_error().unwrap().to_string(),
+        "VAST parse error: tracking event parsing error: 'foo'"
+    );
+    assert_eq!(error.code(), VastErrorCode::SchemaValidation);
+
+    let xml = XML.replace("foo", "complete");
+    let xml = xml.replace("<AdTitle>title</AdTitle>", "");
+    let error = crate::from_str::<crate::Vast>(&xml).unwrap_err();
+    assert!(matches!(error.xml_error(), hard_xml::XmlError::MissingField { .. }));
+    assert_eq!(error.path(), "VAST/Ad[2]/InLine");
+    assert_eq!((error.line(), error.column()), (23, 5));
+
+    let xml = XML.replace("foo", "complete").replace("</Linear>", "</Liner>");
+    let error = crate::from_str::<crate::Vast>(&xml).unwrap_err();
+    assert_eq!(error.path(), "VAST/Ad[2]/InLine/Creatives/Creative[0]/Linear");
+    assert_eq!(error.code(), VastErrorCode::XmlParsing);
+    assert_eq!(error.line(), 20);
+}
@@ -11,6 +11,7 @@
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "Extensions", strict(unknown_attribute, unknown_element))]
 pub struct Extensions {
     /// The container for zero or more [`<Extension>`](Extension) elements.
@@ -33,6 +34,7 @@ pub struct Extensions {
 /// </xs:element>
 /// ```
 #[derive(Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Extension {
     /// The MIME type of any code that might be included in the extension.
     pub mime_type: Option<String>,
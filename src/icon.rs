@@ -10,6 +10,7 @@
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "Icons", strict(unknown_attribute, unknown_element))]
 pub struct Icons<'a> {
     /// The container for one or more [`<Icon>`](Icon) elements.
@@ -45,6 +46,7 @@ pub struct Icons<'a> {
 /// </xs:complexType>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "Icon", strict(unknown_attribute, unknown_element))]
 pub struct Icon<'a> {
     /// The program represented in the icon (e.g. "AdChoices").
@@ -101,6 +103,70 @@ pub struct Icon<'a> {
     pub icon_view_trackings: Vec<std::borrow::Cow<'a, str>>,
 }
 
+impl Icon<'_> {
+    /// Returns the rectangle of the icon within an ad display area of `width` by `height`
+    /// device-independent pixels, or `None` when the icon has no `width` or `height`.
+    ///
+    /// The size of the icon is divided by its `pxratio`. An icon without `xPosition` or
+    /// `yPosition` is placed at the left or top.
+    ///
+    /// ```
+    /// let icon = vast4::Icon {
+    ///     width: Some(40),
+    ///     height: Some(30),
+    ///     x_position: Some(vast4::XPosition::Right),
+    ///     y_position: Some(vast4::YPosition::Coordinate(10)),
+    ///     pxratio: Some(2.0),
+    ///     ..Default::default()
+    /// };
+    /// let rect = vast4::IconRect { x: 620, y: 10, width: 20, height: 15 };
+    /// assert_eq!(icon.layout(640, 360), Some(rect));
+    /// ```
+    pub fn layout(&self, width: u32, height: u32) -> Option<IconRect> {
+        let pxratio = self.pxratio.filter(|&r| r > 0.0).unwrap_or(1.0);
+        let scale = |size: i32| (f64::from(size) / f64::from(pxratio)).round() as i64;
+        let (icon_width, icon_height) = (scale(self.width?), scale(self.height?));
+        let x = match self.x_position.unwrap_or(XPosition::Left) {
+            XPosition::Coordinate(x) => i64::from(x),
+            XPosition::Left => 0,
+            XPosition::Right => i64::from(width) - icon_width,
+        };
+        let y = match self.y_position.unwrap_or(YPosition::Top) {
+            YPosition::Coordinate(y) => i64::from(y),
+            YPosition::Top => 0,
+            YPosition::Bottom => i64::from(height) - icon_height,
+        };
+        let clamp = |v: i64| v.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32;
+        Some(IconRect {
+            x: clamp(x),
+            y: clamp(y),
+            width: clamp(icon_width),
+            height: clamp(icon_height),
+        })
+    }
+
+    /// Returns whether the icon is displayed when the linear creative has played for `playhead`,
+    /// i.e. from its `offset` for its `duration`. Without an `offset` the icon is displayed from
+    /// the start and without a `duration` until the end.
+    pub fn is_visible(&self, playhead: std::time::Duration) -> bool {
+        let offset = self.offset.clone().map(std::time::Duration::from).unwrap_or_default();
+        let duration = self.duration.clone().map(std::time::Duration::from);
+        playhead >= offset && duration.is_none_or(|duration| playhead < offset + duration)
+    }
+}
+
+/// The rectangle of an [`Icon`] within the ad display area, in device-independent pixels, see
+/// [`Icon::layout`].
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct IconRect {
+    /// The x-coordinate of the top, left corner.
+    pub x: i32,
+    /// The y-coordinate of the top, left corner.
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
 /// The x-cooridinate of the top, left corner of the icon asset relative to the ad display area.
 #[derive(PartialEq, Clone, Copy, Debug)]
 pub enum XPosition {
@@ -152,7 +218,7 @@ impl std::str::FromStr for YPosition {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s {
             "top" => Self::Top,
-            "buttom" => Self::Bottom,
+            "bottom" => Self::Bottom,
             _ => match s.parse::<i32>() {
                 Ok(y) => Self::Coordinate(y),
                 Err(_) => {
@@ -190,6 +256,7 @@ impl std::fmt::Display for YPosition {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "IconClicks", strict(unknown_attribute, unknown_element))]
 pub struct IconClicks<'a> {
     /// The container for zero or one [`<IconClickFallbackImages>`](IconClickFallbackImages)
@@ -216,6 +283,7 @@ pub struct IconClicks<'a> {
 /// </xs:complexType>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "IconClickTracking", strict(unknown_attribute, unknown_element))]
 pub struct IconClickTracking<'a> {
     /// An id for the click to be measured.
@@ -241,6 +309,7 @@ pub struct IconClickTracking<'a> {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "IconClickFallbackImages", strict(unknown_attribute, unknown_element))]
 pub struct IconClickFallbackImages<'a> {
     /// The container for one or more [`<IconClickFallbackImage>`](IconClickFallbackImage) element.
@@ -264,6 +333,7 @@ pub struct IconClickFallbackImages<'a> {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "IconClickFallbackImage", strict(unknown_attribute, unknown_element))]
 pub struct IconClickFallbackImage<'a> {
     /// Pixel height of the image asset.
@@ -280,3 +350,26 @@ pub struct IconClickFallbackImage<'a> {
     #[xml(flatten_text = "StaticResource", cdata, default)]
     pub static_resource: Option<std::borrow::Cow<'a, str>>,
 }
+
+#[cfg(test)]
+#[test]
+fn test_icon_layout() {
+    assert_eq!("bottom".parse::<YPosition>().unwrap(), YPosition::Bottom);
+    assert!("buttom".parse::<YPosition>().is_err());
+
+    let icon = crate::from_str::<Icon>(
+        r#"<Icon width="60" height="20" xPosition="left" yPosition="bottom" offset="00:00:05" duration="00:00:10" />"#,
+    )
+    .unwrap();
+    assert_eq!(icon.layout(640, 360), Some(IconRect { x: 0, y: 340, width: 60, height: 20 }));
+    assert_eq!(Icon::default().layout(640, 360), None);
+    let icon = Icon { x_position: Some(XPosition::Coordinate(15)), ..icon };
+    assert_eq!(icon.layout(640, 360), Some(IconRect { x: 15, y: 340, width: 60, height: 20 }));
+
+    let secs = std::time::Duration::from_secs;
+    assert!(!icon.is_visible(secs(4)));
+    assert!(icon.is_visible(secs(5)));
+    assert!(icon.is_visible(secs(14)));
+    assert!(!icon.is_visible(secs(15)));
+    assert!(Icon::default().is_visible(secs(0)));
+}
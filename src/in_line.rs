@@ -26,6 +26,7 @@
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "InLine", strict(unknown_attribute, unknown_element))]
 pub struct InLine<'a> {
     /// The [`<AdSystem>`](AdSystem) element.
@@ -51,9 +52,11 @@ pub struct InLine<'a> {
     /// identifier that is appropriate for all involved parties to track the lifecycle of that ad.
     /// This should be inserted into the `<AdServingId>` element, and also be included on all
     /// outgoing tracking pixels. The value should be different for each Inline in a VAST. Usage of
-    /// a GUID is recommended.
-    #[xml(flatten_text = "AdServingId")]
-    pub ad_serving_id: std::borrow::Cow<'a, str>,
+    /// a GUID is recommended. It is `None` for an ad converted from VAST 3.0. VAST 4.x requires
+    /// it, but a missing element is reported by [`validate`](crate::validate) rather than when
+    /// parsing.
+    #[xml(flatten_text = "AdServingId", default)]
+    pub ad_serving_id: Option<std::borrow::Cow<'a, str>>,
     /// Common name of ad
     #[xml(flatten_text = "AdTitle")]
     pub ad_title: std::borrow::Cow<'a, str>,
@@ -94,6 +97,7 @@ pub struct InLine<'a> {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "AdSystem", strict(unknown_attribute, unknown_element))]
 pub struct AdSystem<'a> {
     /// Internal version used by ad system.
@@ -118,6 +122,7 @@ pub struct AdSystem<'a> {
 /// </xs:complexType>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "Impression", strict(unknown_attribute, unknown_element))]
 pub struct Impression<'a> {
     /// Ad server ID for the impression.
@@ -148,6 +153,7 @@ pub struct Impression<'a> {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "Category", strict(unknown_attribute, unknown_element))]
 pub struct Category<'a> {
     /// A URL for the organizational authority that produced the list being used to identify
@@ -178,6 +184,7 @@ pub struct Category<'a> {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "Pricing", strict(unknown_attribute, unknown_element))]
 pub struct Pricing<'a> {
     /// Identifies the pricing model as one of: CPM, CPC, CPE, or CPV.
@@ -267,6 +274,7 @@ impl std::fmt::Display for PriceModel {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "Survey", strict(unknown_attribute, unknown_element))]
 pub struct Survey<'a> {
     /// The MIME type of the resource being served.
@@ -0,0 +1,430 @@
+use crate::schema::{self, Attributes, Element, Value};
+
+/// Something [`from_str_lenient`] changed in the document to be able to read it.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct LenientWarning {
+    /// The line of the offending element or attribute, starting from 1.
+    pub line: usize,
+    /// The column of the offending element or attribute, starting from 1.
+    pub column: usize,
+    /// The path of the element, e.g. `VAST/Ad/InLine/Creatives/Creative/Linear`.
+    pub path: String,
+    pub kind: LenientWarningKind,
+}
+
+/// The kind of a [`LenientWarning`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum LenientWarningKind {
+    /// The element is not part of the model and was skipped with its content.
+    UnknownElement(String),
+    /// The attribute is not part of the model and was skipped.
+    UnknownAttribute(String),
+    /// The name of the element or attribute differs from the model in case only, e.g. `Inline`.
+    Renamed { from: String, to: &'static str },
+    /// The value of the attribute was trimmed or differs from the model in case only, e.g. `cpm`.
+    Normalized { attribute: &'static str, from: String, to: String },
+}
+
+impl std::fmt::Display for LenientWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{} {}: ", self.line, self.column, self.path)?;
+        match &self.kind {
+            LenientWarningKind::UnknownElement(name) => {
+                write!(f, "skipped unknown element <{name}>")
+            }
+            LenientWarningKind::UnknownAttribute(name) => {
+                write!(f, "skipped unknown attribute '{name}'")
+            }
+            LenientWarningKind::Renamed { from, to } => write!(f, "renamed '{from}' to '{to}'"),
+            LenientWarningKind::Normalized { attribute, from, to } => {
+                write!(f, "normalized {attribute}=\"{from}\" to \"{to}\"")
+            }
+        }
+    }
+}
+
+/// Reads a VAST document like [`AnyVast::parse`](crate::AnyVast::parse), tolerating the
+/// deviations found in real-world responses:
+///
+/// - unknown elements and attributes are skipped,
+/// - element and attribute names are matched ignoring case, e.g. `<Inline>`,
+/// - enumerated and boolean attribute values are trimmed and matched ignoring case, e.g.
+///   `model="cpm"` or `scalable="TRUE"`.
+///
+/// A document whose `version` is 3.0 is read with the [`v3`](crate::v3) model, e.g. with its
+/// `fullscreen` tracking event, any other with the VAST 4.x one.
+///
+/// The document is rewritten into `buf`, which the returned value borrows from. The content of
+/// [`<Extension>`](crate::Extension) and [`<CreativeExtension>`](crate::CreativeExtension) is kept
+/// as is. Errors the rewrite can not fix, e.g. a missing required element, are still returned,
+/// at their position in `s`.
+pub fn from_str_lenient<'a>(
+    s: &str,
+    buf: &'a mut String,
+) -> crate::Result<(crate::AnyVast<'a>, Vec<LenientWarning>)> {
+    let v3 = crate::vast::read_version(s).is_ok_and(|v| v == crate::VastVersion::V3_0);
+    let mut sanitizer = Sanitizer {
+        src: s,
+        root: if v3 { schema::v3::ROOT } else { schema::ROOT },
+        out: String::with_capacity(s.len()),
+        ..Default::default()
+    };
+    // only malformed XML fails here, which is located without parsing again
+    sanitizer.run().map_err(|e| crate::Error::locate(s, e, |_| Ok(())))?;
+    *buf = std::mem::take(&mut sanitizer.out);
+    let buf: &'a str = buf;
+    let vast = match v3 {
+        true => crate::from_str(buf).map(crate::AnyVast::V3),
+        false => crate::from_str(buf).map(crate::AnyVast::V4),
+    };
+    let vast =
+        vast.map_err(|e| e.relocate(buf, s, |offset| sanitizer.source_offset(offset, buf.len())))?;
+    Ok((vast, sanitizer.warnings))
+}
+
+enum Frame {
+    Known { element: &'static Element, name: &'static str },
+    // inside a raw element, written as is
+    Raw,
+    // inside an unknown element, not written
+    Skipped,
+}
+
+#[derive(Default)]
+struct Sanitizer<'s> {
+    src: &'s str,
+    root: &'static [(&'static str, &'static Element)],
+    out: String,
+    warnings: Vec<LenientWarning>,
+    stack: Vec<Frame>,
+    path: Vec<&'static str>,
+    // the offset in `src` of the token being read
+    pos: usize,
+    // the offsets in `out` and `src` where the output of each written token starts
+    offsets: Vec<(usize, usize)>,
+}
+
+impl<'s> Sanitizer<'s> {
+    fn run(&mut self) -> hard_xml::XmlResult<()> {
+        use hard_xml::xmlparser::{ElementEnd, Token, Tokenizer};
+
+        for token in Tokenizer::from(self.src) {
+            let token = token?;
+            self.pos = crate::error::token_span(&token).start();
+            match token {
+                Token::ElementStart { prefix, local, span } => {
+                    let name = match prefix.is_empty() {
+                        true => local.as_str(),
+                        false => &span.as_str()[1..],
+                    };
+                    self.element_start(name, span.start());
+                }
+                Token::Attribute { prefix, local, value, span } => {
+                    let key = match prefix.is_empty() {
+                        true => local.as_str(),
+                        false => &span.as_str()[..prefix.len() + 1 + local.len()],
+                    };
+                    self.attribute(key, value.as_str(), span.as_str(), span.start());
+                }
+                Token::ElementEnd { end, span } => match end {
+                    ElementEnd::Open => self.write(span.as_str()),
+                    ElementEnd::Empty => {
+                        self.write(span.as_str());
+                        self.pop();
+                    }
+                    ElementEnd::Close(..) => {
+                        match self.stack.last() {
+                            Some(Frame::Known { name, .. }) => {
+                                let name = *name;
+                                self.write("</");
+                                self.write(name);
+                                self.write(">");
+                            }
+                            _ => self.write(span.as_str()),
+                        }
+                        self.pop();
+                    }
+                },
+                Token::Text { text } => self.write(text.as_str()),
+                Token::Declaration { span, .. }
+                | Token::ProcessingInstruction { span, .. }
+                | Token::Comment { span, .. }
+                | Token::DtdStart { span, .. }
+                | Token::EmptyDtd { span, .. }
+                | Token::EntityDeclaration { span, .. }
+                | Token::DtdEnd { span }
+                | Token::Cdata { span, .. } => self.write(span.as_str()),
+            }
+        }
+        Ok(())
+    }
+
+    fn element_start(&mut self, name: &str, pos: usize) {
+        let children = match self.stack.last() {
+            None => self.root,
+            Some(Frame::Known { element, .. }) if !element.raw => element.children,
+            Some(Frame::Known { .. } | Frame::Raw) => &[],
+            Some(Frame::Skipped) => return self.stack.push(Frame::Skipped),
+        };
+        let frame = match self.stack.last() {
+            Some(Frame::Known { element, .. }) if element.raw => Frame::Raw,
+            Some(Frame::Raw) => Frame::Raw,
+            _ => self.child(name, children, pos),
+        };
+        match frame {
+            Frame::Known { name, element } => {
+                self.write("<");
+                self.write(name);
+                self.path.push(name);
+                self.stack.push(Frame::Known { name, element });
+            }
+            Frame::Raw => {
+                self.write("<");
+                self.write(name);
+                self.stack.push(frame);
+            }
+            Frame::Skipped => self.stack.push(Frame::Skipped),
+        }
+    }
+
+    fn child(
+        &mut self,
+        name: &str,
+        children: &'static [(&'static str, &'static Element)],
+        pos: usize,
+    ) -> Frame {
+        match lookup(children, name, pos, self) {
+            Some(&(name, element)) => Frame::Known { element, name },
+            None => {
+                self.warn(pos, LenientWarningKind::UnknownElement(name.to_owned()));
+                Frame::Skipped
+            }
+        }
+    }
+
+    fn attribute(&mut self, key: &str, value: &str, span: &str, pos: usize) {
+        let element = match self.stack.last() {
+            Some(Frame::Known { element, .. }) => *element,
+            Some(Frame::Raw) => return self.write_attribute(span),
+            Some(Frame::Skipped) | None => return,
+        };
+        let attributes = match element.attributes {
+            Attributes::Any => return self.write_attribute(span),
+            Attributes::Only(attributes) => attributes,
+        };
+        // namespace declarations are dropped silently
+        if key == "xmlns" || key.starts_with("xmlns:") {
+            return;
+        }
+
+        let (key, value_type) = match lookup(attributes, key, pos, self) {
+            Some(&(key, value_type)) => (key, value_type),
+            None => {
+                self.warn(pos, LenientWarningKind::UnknownAttribute(key.to_owned()));
+                return;
+            }
+        };
+        let normalized = match value_type {
+            Value::Text => None,
+            Value::Bool => {
+                let lower = value.trim().to_ascii_lowercase();
+                (lower != value).then_some(lower)
+            }
+            Value::Enum(values) => {
+                let trimmed = value.trim();
+                let exact = values.iter().find(|v| **v == trimmed);
+                match exact.or_else(|| values.iter().find(|v| v.eq_ignore_ascii_case(trimmed))) {
+                    Some(v) => (*v != value).then(|| v.to_string()),
+                    None => (trimmed != value).then(|| trimmed.to_owned()),
+                }
+            }
+        };
+        match normalized {
+            Some(to) => {
+                self.warn(
+                    pos,
+                    LenientWarningKind::Normalized {
+                        attribute: key,
+                        from: value.to_owned(),
+                        to: to.clone(),
+                    },
+                );
+                self.write(" ");
+                self.write(key);
+                self.write("=\"");
+                self.write(&to);
+                self.write("\"");
+            }
+            None => {
+                self.write(" ");
+                self.write(key);
+                self.write(&span[key.len()..]);
+            }
+        }
+    }
+
+    fn write_attribute(&mut self, span: &str) {
+        self.write(" ");
+        self.write(span);
+    }
+
+    fn write(&mut self, s: &str) {
+        if !matches!(self.stack.last(), Some(Frame::Skipped)) {
+            if self.offsets.last().is_none_or(|&(_, pos)| pos != self.pos) {
+                self.offsets.push((self.out.len(), self.pos));
+            }
+            self.out.push_str(s);
+        }
+    }
+
+    // Returns the offset in `src` of the token written at `offset` in the output of length `len`.
+    fn source_offset(&self, offset: usize, len: usize) -> usize {
+        if offset >= len {
+            return self.src.len();
+        }
+        let i = self.offsets.partition_point(|&(out, _)| out <= offset);
+        i.checked_sub(1).map_or(0, |i| self.offsets[i].1)
+    }
+
+    fn pop(&mut self) {
+        if let Some(Frame::Known { .. }) = self.stack.pop() {
+            self.path.pop();
+        }
+    }
+
+    fn warn(&mut self, pos: usize, kind: LenientWarningKind) {
+        let (line, column) = crate::error::position(self.src, pos);
+        self.warnings.push(LenientWarning { line, column, path: self.path.join("/"), kind });
+    }
+}
+
+// Finds `name` in `entries`, ignoring case if there is no exact match.
+fn lookup<'e, V>(
+    entries: &'e [(&'static str, V)],
+    name: &str,
+    pos: usize,
+    sanitizer: &mut Sanitizer,
+) -> Option<&'e (&'static str, V)> {
+    if let Some(entry) = entries.iter().find(|(n, _)| *n == name) {
+        return Some(entry);
+    }
+    let entry = entries.iter().find(|(n, _)| n.eq_ignore_ascii_case(name))?;
+    sanitizer.warn(pos, LenientWarningKind::Renamed { from: name.to_owned(), to: entry.0 });
+    Some(entry)
+}
+
+#[cfg(test)]
+#[test]
+fn test_from_str_lenient() {
+    const XML: &str = r#"<VAST version="4.2" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+  <Ad id="1" vendor="acme">
+    <Inline>
+      <AdSystem>iabtechlab</AdSystem>
+      <AdTitle>title</AdTitle>
+      <Impression><![CDATA[https://example.com/impression]]></Impression>
+      <AdServingId>id</AdServingId>
+      <Pricing model="Cpm" currency="USD">25.00</Pricing>
+      <Extensions>
+        <Extension type="acme"><Anything unknown="1"/></Extension>
+      </Extensions>
+      <Creatives>
+        <Creative>
+          <Linear>
+            <Duration>00:00:16</Duration>
+            <TrackingEvents>
+              <Tracking event=" FirstQuartile "><![CDATA[https://example.com/q1]]></Tracking>
+            </TrackingEvents>
+            <MediaFiles>
+              <MediaFile delivery="Progressive" type="video/mp4" width="640" height="360" scalable="TRUE" acme:quality="high"><![CDATA[https://example.com/video.mp4]]></MediaFile>
+              <AcmeFile><MediaFile/></AcmeFile>
+            </MediaFiles>
+          </Linear>
+        </Creative>
+      </Creatives>
+    </Inline>
+  </Ad>
+</VAST>"#;
+
+    assert!(crate::from_str::<crate::Vast>(XML).is_err());
+
+    let mut buf = String::new();
+    let (vast, warnings) = from_str_lenient(XML, &mut buf).unwrap();
+    let crate::AnyVast::V4(vast) = vast else { panic!("not read as VAST 4.x") };
+
+    let in_line = vast.ads[0].in_line.as_ref().unwrap();
+    assert_eq!(in_line.pricing.as_ref().unwrap().model, crate::PriceModel::Cpm);
+    assert_eq!(
+        in_line.extensions.as_ref().unwrap().extensions[0].xml,
+        r#"<Anything unknown="1"/>"#
+    );
+    let linear = in_line.creatives.creatives[0].linear.as_ref().unwrap();
+    assert_eq!(
+        linear.tracking_events.as_ref().unwrap().trackings[0].event,
+        crate::TrackingEvent::FirstQuartile
+    );
+    let media_files = &linear.media_files.as_ref().unwrap().media_files;
+    assert_eq!(media_files.len(), 1);
+    assert_eq!(media_files[0].delivery, crate::DeliveryProtocol::Progressive);
+    assert_eq!(media_files[0].scalable, Some(true));
+
+    assert_eq!(
+        warnings.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        [
+            r#"2:14 VAST/Ad: skipped unknown attribute 'vendor'"#,
+            r#"3:5 VAST/Ad: renamed 'Inline' to 'InLine'"#,
+            r#"8:16 VAST/Ad/InLine/Pricing: normalized model="Cpm" to "cpm""#,
+            r#"17:25 VAST/Ad/InLine/Creatives/Creative/Linear/TrackingEvents/Tracking: normalized event=" FirstQuartile " to "firstQuartile""#,
+            r#"20:26 VAST/Ad/InLine/Creatives/Creative/Linear/MediaFiles/MediaFile: normalized delivery="Progressive" to "progressive""#,
+            r#"20:91 VAST/Ad/InLine/Creatives/Creative/Linear/MediaFiles/MediaFile: normalized scalable="TRUE" to "true""#,
+            r#"20:107 VAST/Ad/InLine/Creatives/Creative/Linear/MediaFiles/MediaFile: skipped unknown attribute 'acme:quality'"#,
+            r#"21:15 VAST/Ad/InLine/Creatives/Creative/Linear/MediaFiles: skipped unknown element <AcmeFile>"#,
+        ]
+    );
+
+    // the errors the rewrite can not fix are located in the source
+    const INVALID: &str = r#"<VAST version="4.2">
+  <Ad><Inline><AdSystem>iabtechlab</AdSystem>
+    <Acme><Data/></Acme><Pricing model="CPM" currency="USD" acme:floor="1">abc</Pricing>
+  </Inline></Ad>
+</VAST>"#;
+    let error = from_str_lenient(INVALID, &mut buf).unwrap_err();
+    assert_eq!((error.line(), error.column(), error.path()), (3, 76, "VAST/Ad[0]/InLine/Pricing"));
+    let error = from_str_lenient(&INVALID.replace("abc", "25.00"), &mut buf).unwrap_err();
+    assert!(matches!(error.xml_error(), hard_xml::XmlError::MissingField { .. }));
+    assert_eq!((error.line(), error.column(), error.path()), (4, 3, "VAST/Ad[0]/InLine"));
+
+    // a VAST 3.0 document is read with its own tracking events
+    const V3: &str = r#"<VAST version="3.0">
+  <Ad id="1">
+    <InLine>
+      <AdSystem>iabtechlab</AdSystem>
+      <AdTitle>title</AdTitle>
+      <Impression><![CDATA[https://example.com/impression]]></Impression>
+      <Creatives>
+        <Creative AdID="8465" acme:id="1">
+          <Linear>
+            <Duration>00:00:16</Duration>
+            <TrackingEvents>
+              <Tracking event="Fullscreen"><![CDATA[https://example.com/fullscreen]]></Tracking>
+            </TrackingEvents>
+          </Linear>
+        </Creative>
+      </Creatives>
+    </InLine>
+  </Ad>
+</VAST>"#;
+
+    let (vast, warnings) = from_str_lenient(V3, &mut buf).unwrap();
+    let crate::AnyVast::V3(vast) = vast else { panic!("not read as VAST 3.0") };
+    let creative = &vast.ads[0].in_line.as_ref().unwrap().creatives.creatives[0];
+    assert_eq!(creative.ad_id.as_deref(), Some("8465"));
+    let tracking_events = creative.linear.as_ref().unwrap().tracking_events.as_ref();
+    assert_eq!(tracking_events.unwrap().trackings[0].event, crate::v3::TrackingEvent::Fullscreen);
+    assert_eq!(
+        warnings.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        [
+            r#"8:31 VAST/Ad/InLine/Creatives/Creative: skipped unknown attribute 'acme:id'"#,
+            r#"12:25 VAST/Ad/InLine/Creatives/Creative/Linear/TrackingEvents/Tracking: normalized event="Fullscreen" to "fullscreen""#,
+        ]
+    );
+}
@@ -3,6 +3,14 @@
 
 //! An implementation of [`VAST 4.2 FINAL`].
 //!
+//! VAST 4.0 to 4.2 documents are read with the same model. VAST 3.0 documents are read with the
+//! [`v3`] module, and [`AnyVast`] picks the model from the `version` attribute. VMAP 1.0 ad
+//! break playlists are read with the [`vmap`] module.
+//!
+//! With the `serde` feature, the model implements `Serialize` and `Deserialize`. Values with a
+//! string form in the spec, such as [`TrackingEvent`] or [`Duration`], are serialized as that
+//! string.
+//!
 //! [`VAST 4.2 FINAL`]: https://iabtechlab.com/wp-content/uploads/2019/06/VAST_4.2_final_june26.pdf
 
 mod macros;
@@ -13,8 +21,16 @@ pub fn to_string<T: hard_xml::XmlWrite>(value: &T) -> hard_xml::XmlResult<String
     T::to_string(value)
 }
 
-pub fn from_str<'a, T: hard_xml::XmlRead<'a>>(s: &'a str) -> hard_xml::XmlResult<T> {
-    T::from_str(s)
+/// Writes `value` to `writer`, see [`WriteOptions`] for other formats.
+pub fn to_writer<T: hard_xml::XmlWrite, W: std::io::Write>(
+    value: &T,
+    writer: W,
+) -> hard_xml::XmlResult<()> {
+    WriteOptions::default().to_writer(value, writer)
+}
+
+pub fn from_str<'a, T: hard_xml::XmlRead<'a>>(s: &'a str) -> Result<T> {
+    T::from_str(s).map_err(|e| Error::locate(s, e, |s| T::from_str(s).map(drop)))
 }
 
 // common types
@@ -24,6 +40,9 @@ pub use duration::Duration;
 mod error;
 pub use error::*;
 
+mod writer;
+pub use writer::WriteOptions;
+
 // 3.2
 mod vast;
 pub use vast::*;
@@ -52,6 +71,9 @@ pub use linear::*;
 mod media_file;
 pub use media_file::*;
 
+mod mime_type;
+pub use mime_type::*;
+
 // 3.10
 mod click;
 pub use click::*;
@@ -60,6 +82,8 @@ pub use click::*;
 mod icon;
 pub use icon::*;
 
+mod ad_choices;
+
 // 3.12
 mod non_linear;
 pub use non_linear::*;
@@ -87,3 +111,53 @@ pub use extension::*;
 // 3.19
 mod wrapper;
 pub use wrapper::*;
+
+// VAST 3.0
+pub mod v3;
+
+// VMAP 1.0
+pub mod vmap;
+
+// utilities
+mod resolver;
+pub use resolver::*;
+
+mod merge;
+pub use merge::*;
+
+mod pod;
+pub use pod::*;
+
+mod macro_expansion;
+pub use macro_expansion::*;
+
+mod tracker;
+pub use tracker::*;
+
+mod selector;
+pub use selector::*;
+
+mod companion_selector;
+pub use companion_selector::*;
+
+mod validate;
+pub use validate::*;
+
+mod schema;
+
+mod lenient;
+pub use lenient::*;
+
+pub mod preserve;
+
+mod owned;
+pub use owned::*;
+
+mod builder;
+pub use builder::*;
+
+mod url;
+pub use url::*;
+
+#[cfg(feature = "serde")]
+mod serde_str;
@@ -42,6 +42,7 @@
 /// </xs:complexType>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "Linear", strict(unknown_attribute, unknown_element))]
 pub struct Linear<'a> {
     /// The time at which the ad becomes skippable, if absent, the ad is not skippable.
@@ -89,6 +90,7 @@ pub struct Linear<'a> {
 /// </xs:complexType>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "AdParameters", strict(unknown_attribute, unknown_element))]
 pub struct AdParameters<'a> {
     /// Identifies whether the ad parameters are xml-encoded.
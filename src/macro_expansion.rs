@@ -0,0 +1,301 @@
+/// What to substitute for a macro that cannot be expanded.
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum UnknownMacro {
+    /// Leave the macro in the URI as it is.
+    #[default]
+    Keep,
+    /// Replace the macro with `-1`, the value the specification uses for unknown information.
+    MinusOne,
+}
+
+/// The values the media player knows when it is about to request a URI (Sec 6.1).
+///
+/// A `None` or empty field means the value is unknown.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct MacroContext {
+    /// `[TIMESTAMP]`: the date and time at which the URI was generated.
+    pub timestamp: Option<std::time::SystemTime>,
+    /// `[CACHEBUSTING]`: a random 8-digit integer.
+    pub cache_busting: Option<u32>,
+    /// `[ERRORCODE]`: the error that occurred.
+    pub error_code: Option<crate::VastErrorCode>,
+    /// `[REASON]`: the reason a verification resource was not executed.
+    pub reason: Option<u32>,
+    /// `[CONTENTPLAYHEAD]`: the playhead of the content, deprecated in favour of
+    /// `[MEDIAPLAYHEAD]`.
+    pub content_playhead: Option<crate::Duration>,
+    /// `[MEDIAPLAYHEAD]`: the playhead of the content the ad is shown with.
+    pub media_playhead: Option<crate::Duration>,
+    /// `[ADPLAYHEAD]`: the playhead of the ad.
+    pub ad_playhead: Option<crate::Duration>,
+    /// `[BREAKPOSITION]`: `1` for pre-roll, `2` for mid-roll, `3` for post-roll and `4` for
+    /// standalone.
+    pub break_position: Option<u32>,
+    /// `[PODSEQUENCE]`: the sequence of the ad in its pod.
+    pub pod_sequence: Option<u32>,
+    /// `[ADCOUNT]`: the number of ads played in the break so far.
+    pub ad_count: Option<u32>,
+    /// `[ASSETURI]`: the URI of the creative file being played.
+    pub asset_uri: Option<String>,
+    /// `[ADSERVINGID]`: the value of the `<AdServingId>` element.
+    pub ad_serving_id: Option<String>,
+    /// `[UNIVERSALADID]`: the registry and the identifier of the ad, separated by a space.
+    pub universal_ad_id: Option<String>,
+    /// `[TRANSACTIONID]`: an identifier shared by every request of the ad session.
+    pub transaction_id: Option<String>,
+    /// `[PAGEURL]`: the URL of the page the ad is shown on.
+    pub page_url: Option<String>,
+    /// `[DOMAIN]`: the domain of the page the ad is shown on.
+    pub domain: Option<String>,
+    /// `[APPBUNDLE]`: the bundle identifier of the application the ad is shown in.
+    pub app_bundle: Option<String>,
+    /// `[IFA]`: the resettable advertising identifier of the device.
+    pub ifa: Option<String>,
+    /// `[IFATYPE]`: the type of the advertising identifier.
+    pub ifa_type: Option<String>,
+    /// `[DEVICEUA]`: the user agent of the device.
+    pub device_ua: Option<String>,
+    /// `[DEVICEIP]`: the IP address of the device.
+    pub device_ip: Option<String>,
+    /// `[LIMITADTRACKING]`: whether the user opted out of ad tracking.
+    pub limit_ad_tracking: Option<bool>,
+    /// `[GDPRCONSENT]`: the base64 encoded consent string.
+    pub gdpr_consent: Option<String>,
+    /// `[PLAYERSIZE]`: the width and height of the player, in pixels.
+    pub player_size: Option<(u32, u32)>,
+    /// `[PLAYERSTATE]`: the states of the player, e.g. `fullscreen` or `muted`.
+    pub player_state: Vec<String>,
+    /// `[ADCATEGORIES]`: the categories of the ad.
+    pub ad_categories: Vec<String>,
+    /// `[BLOCKEDADCATEGORIES]`: the categories blocked by the publisher.
+    pub blocked_ad_categories: Vec<String>,
+    /// Values of any other macro, keyed by the name without the square brackets.
+    pub custom: std::collections::BTreeMap<String, String>,
+}
+
+/// The result of [`expand_macros`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct MacroExpansion {
+    /// The URI with the macros substituted.
+    pub uri: String,
+    /// The macros found in the URI, in order of appearance.
+    pub macros: Vec<MacroMatch>,
+}
+
+/// A macro found in a URI.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct MacroMatch {
+    /// The name of the macro, without the square brackets.
+    pub name: String,
+    pub status: MacroStatus,
+}
+
+/// Whether a macro found in a URI could be expanded.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum MacroStatus {
+    /// The macro was replaced with its value.
+    Replaced,
+    /// The macro is known, but the context holds no value for it.
+    Missing,
+    /// The macro is not known.
+    Unknown,
+}
+
+enum MacroValue {
+    Missing,
+    Unknown,
+    Value(String),
+    List(Vec<String>),
+}
+
+impl MacroContext {
+    fn value(&self, name: &str) -> MacroValue {
+        fn opt<T: ToString>(value: &Option<T>) -> MacroValue {
+            value.as_ref().map_or(MacroValue::Missing, |v| MacroValue::Value(v.to_string()))
+        }
+        fn list(values: &[String]) -> MacroValue {
+            match values.is_empty() {
+                true => MacroValue::Missing,
+                false => MacroValue::List(values.to_vec()),
+            }
+        }
+
+        match name {
+            "TIMESTAMP" => opt(&self.timestamp.map(timestamp)),
+            "CACHEBUSTING" => opt(&self.cache_busting.map(|v| format!("{:08}", v % 100_000_000))),
+            "ERRORCODE" => opt(&self.error_code),
+            "REASON" => opt(&self.reason),
+            "CONTENTPLAYHEAD" => opt(&self.content_playhead.as_ref().map(playhead)),
+            "MEDIAPLAYHEAD" => opt(&self.media_playhead.as_ref().map(playhead)),
+            "ADPLAYHEAD" => opt(&self.ad_playhead.as_ref().map(playhead)),
+            "BREAKPOSITION" => opt(&self.break_position),
+            "PODSEQUENCE" => opt(&self.pod_sequence),
+            "ADCOUNT" => opt(&self.ad_count),
+            "ASSETURI" => opt(&self.asset_uri),
+            "ADSERVINGID" => opt(&self.ad_serving_id),
+            "UNIVERSALADID" => opt(&self.universal_ad_id),
+            "TRANSACTIONID" => opt(&self.transaction_id),
+            "PAGEURL" => opt(&self.page_url),
+            "DOMAIN" => opt(&self.domain),
+            "APPBUNDLE" => opt(&self.app_bundle),
+            "IFA" => opt(&self.ifa),
+            "IFATYPE" => opt(&self.ifa_type),
+            "DEVICEUA" => opt(&self.device_ua),
+            "DEVICEIP" => opt(&self.device_ip),
+            "LIMITADTRACKING" => opt(&self.limit_ad_tracking.map(u8::from)),
+            "GDPRCONSENT" => opt(&self.gdpr_consent),
+            "PLAYERSIZE" => opt(&self.player_size.map(|(w, h)| format!("{w},{h}"))),
+            "PLAYERSTATE" => list(&self.player_state),
+            "ADCATEGORIES" => list(&self.ad_categories),
+            "BLOCKEDADCATEGORIES" => list(&self.blocked_ad_categories),
+            _ => match self.custom.get(name) {
+                Some(v) => MacroValue::Value(v.clone()),
+                None => MacroValue::Unknown,
+            },
+        }
+    }
+}
+
+/// Substitutes the macros of `uri` with the values of `context`.
+///
+/// Values are percent-encoded as RFC 3986 requires, list values are encoded item by item and
+/// joined with `,`. Macros that cannot be expanded are handled according to `unknown`.
+pub fn expand_macros(uri: &str, context: &MacroContext, unknown: UnknownMacro) -> MacroExpansion {
+    let mut expanded = String::with_capacity(uri.len());
+    let mut macros = Vec::new();
+    let mut rest = uri;
+
+    while let Some(start) = rest.find('[') {
+        expanded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let name = match rest[1..].find(']') {
+            Some(end) if is_macro_name(&rest[1..=end]) => &rest[1..=end],
+            _ => {
+                expanded.push('[');
+                rest = &rest[1..];
+                continue;
+            }
+        };
+        let raw = &rest[..name.len() + 2];
+        rest = &rest[raw.len()..];
+
+        let status = match context.value(name) {
+            MacroValue::Value(value) => {
+                encode_into(&mut expanded, &value);
+                MacroStatus::Replaced
+            }
+            MacroValue::List(values) => {
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        expanded.push(',');
+                    }
+                    encode_into(&mut expanded, value);
+                }
+                MacroStatus::Replaced
+            }
+            MacroValue::Missing => MacroStatus::Missing,
+            MacroValue::Unknown => MacroStatus::Unknown,
+        };
+        if status != MacroStatus::Replaced {
+            match unknown {
+                UnknownMacro::Keep => expanded.push_str(raw),
+                UnknownMacro::MinusOne => expanded.push_str("-1"),
+            }
+        }
+        macros.push(MacroMatch { name: name.to_owned(), status });
+    }
+    expanded.push_str(rest);
+
+    MacroExpansion { uri: expanded, macros }
+}
+
+fn is_macro_name(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'_')
+}
+
+fn encode_into(buf: &mut String, value: &str) {
+    for b in value.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                buf.push(b as char)
+            }
+            _ => buf.push_str(&format!("%{b:02X}")),
+        }
+    }
+}
+
+fn playhead(duration: &crate::Duration) -> String {
+    let duration = std::time::Duration::from(duration.clone());
+    let secs = duration.as_secs();
+    let (hh, mm, ss) = (secs / 3600, secs / 60 % 60, secs % 60);
+    format!("{hh:02}:{mm:02}:{ss:02}.{:03}", duration.subsec_millis())
+}
+
+// Formats as `YYYY-MM-DDThh:mm:ss.sssZ`.
+fn timestamp(time: std::time::SystemTime) -> String {
+    let since_epoch = time.duration_since(std::time::UNIX_EPOCH).unwrap_or_default();
+    let secs = since_epoch.as_secs();
+    let (hh, mm, ss) = (secs / 3600 % 24, secs / 60 % 60, secs % 60);
+
+    // https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = (secs / 86400) as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{hh:02}:{mm:02}:{ss:02}.{:03}Z",
+        since_epoch.subsec_millis()
+    )
+}
+
+#[cfg(test)]
+#[test]
+fn test_expand_macros() {
+    let context = MacroContext {
+        timestamp: Some(std::time::UNIX_EPOCH + std::time::Duration::from_millis(1453018507127)),
+        cache_busting: Some(1234),
+        error_code: Some(crate::VastErrorCode::WrapperLimit),
+        ad_playhead: Some(crate::Duration::new(0, 0, 5, 0)),
+        asset_uri: Some("https://example.com/video.mp4?a=1".into()),
+        ad_categories: vec!["IAB1-1".into(), "a b".into()],
+        ..Default::default()
+    };
+
+    let expansion = expand_macros(
+        "https://example.com/t?ts=[TIMESTAMP]&cb=[CACHEBUSTING]&e=[ERRORCODE]&p=[ADPLAYHEAD]\
+         &u=[ASSETURI]&c=[ADCATEGORIES]&r=[REASON]&x=[FOO]&[not a macro]",
+        &context,
+        UnknownMacro::Keep,
+    );
+    assert_eq!(
+        expansion.uri,
+        "https://example.com/t?ts=2016-01-17T08%3A15%3A07.127Z&cb=00001234&e=302\
+         &p=00%3A00%3A05.000&u=https%3A%2F%2Fexample.com%2Fvideo.mp4%3Fa%3D1&c=IAB1-1,a%20b\
+         &r=[REASON]&x=[FOO]&[not a macro]"
+    );
+    let statuses = expansion.macros.iter().map(|m| (&*m.name, m.status)).collect::<Vec<_>>();
+    assert_eq!(
+        statuses,
+        [
+            ("TIMESTAMP", MacroStatus::Replaced),
+            ("CACHEBUSTING", MacroStatus::Replaced),
+            ("ERRORCODE", MacroStatus::Replaced),
+            ("ADPLAYHEAD", MacroStatus::Replaced),
+            ("ASSETURI", MacroStatus::Replaced),
+            ("ADCATEGORIES", MacroStatus::Replaced),
+            ("REASON", MacroStatus::Missing),
+            ("FOO", MacroStatus::Unknown),
+        ]
+    );
+
+    let expansion =
+        expand_macros("https://example.com/t?r=[REASON]&x=[FOO]", &context, UnknownMacro::MinusOne);
+    assert_eq!(expansion.uri, "https://example.com/t?r=-1&x=-1");
+}
@@ -1,3 +1,4 @@
+#[allow(unused_imports)]
 pub use impls::*;
 
 #[macro_use]
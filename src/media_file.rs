@@ -15,6 +15,7 @@
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "MediaFiles", strict(unknown_attribute, unknown_element))]
 pub struct MediaFiles<'a> {
     /// The container for zero or one [`<ClosedCaptionFiles>`](ClosedCaptionFiles) element.
@@ -60,6 +61,7 @@ pub struct MediaFiles<'a> {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "MediaFile", strict(unknown_attribute, unknown_element))]
 pub struct MediaFile<'a> {
     /// An identifier for the media file.
@@ -69,9 +71,9 @@ pub struct MediaFile<'a> {
     /// “streaming” for streaming protocols.
     #[xml(attr = "delivery")]
     pub delivery: DeliveryProtocol,
-    // TODO: typed value
     /// MIME type for the file container. Popular MIME types include, but are not
     /// limited to “video/mp4” for MP4, “audio/mpeg” and "audio/aac" for audio ads.
+    /// See [`MediaFile::mime`] for the typed value.
     #[xml(attr = "type")]
     pub mime_type: std::borrow::Cow<'a, str>,
     /// The native width of the video file, in pixels. (0 for audio ads)
@@ -80,9 +82,9 @@ pub struct MediaFile<'a> {
     /// The native height of the video file, in pixels. (0 for audio ads)
     #[xml(attr = "height")]
     pub height: i32,
-    // TODO: typed value
     /// The codec used to encode the file which can take values as specified by
     /// [RFC 4281](http://tools.ietf.org/html/rfc4281).
+    /// See [`MediaFile::codecs`] for the typed value.
     #[xml(attr = "codec", default)]
     pub codec: Option<std::borrow::Cow<'a, str>>,
     /// For progressive load video or audio, the `bitrate` value specifies the average
@@ -130,6 +132,18 @@ pub struct MediaFile<'a> {
     pub uri: std::borrow::Cow<'a, str>,
 }
 
+impl MediaFile<'_> {
+    /// Returns the typed MIME type of the file.
+    pub fn mime(&self) -> crate::MimeType {
+        self.mime_type.parse().unwrap_or_else(|e| match e {})
+    }
+
+    /// Returns the typed codecs of the file, empty when there is no `codec`.
+    pub fn codecs(&self) -> Result<Vec<crate::Codec>, crate::VastParseError> {
+        self.codec.as_deref().map_or(Ok(Vec::new()), crate::Codec::parse_list)
+    }
+}
+
 /// Represents the video delivery protocol type.
 ///
 /// ```text
@@ -195,6 +209,7 @@ impl std::fmt::Display for DeliveryProtocol {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "Mezzanine", strict(unknown_attribute, unknown_element))]
 pub struct Mezzanine<'a> {
     /// An identifier for the media file.
@@ -234,6 +249,18 @@ pub struct Mezzanine<'a> {
     pub uri: std::borrow::Cow<'a, str>,
 }
 
+impl Mezzanine<'_> {
+    /// Returns the typed MIME type of the file.
+    pub fn mime(&self) -> crate::MimeType {
+        self.mime_type.parse().unwrap_or_else(|e| match e {})
+    }
+
+    /// Returns the typed codecs of the file, empty when there is no `codec`.
+    pub fn codecs(&self) -> Result<Vec<crate::Codec>, crate::VastParseError> {
+        self.codec.as_deref().map_or(Ok(Vec::new()), crate::Codec::parse_list)
+    }
+}
+
 /// The `<InteractiveCreativeFile>` element is used to identify the file and the framework
 /// needed for execution.
 ///
@@ -251,6 +278,7 @@ pub struct Mezzanine<'a> {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "InteractiveCreativeFile", strict(unknown_attribute, unknown_element))]
 pub struct InteractiveCreativeFile<'a> {
     /// Identifies the MIME type of the file provided.
@@ -283,6 +311,7 @@ pub struct InteractiveCreativeFile<'a> {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "ClosedCaptionFiles", strict(unknown_attribute, unknown_element))]
 pub struct ClosedCaptionFiles<'a> {
     /// The container for zero or more [`<ClosedCaptionFile>`](ClosedCaptionFile) element.
@@ -305,6 +334,7 @@ pub struct ClosedCaptionFiles<'a> {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "ClosedCaptionFile", strict(unknown_attribute, unknown_element))]
 pub struct ClosedCaptionFile<'a> {
     /// Identifies the MIME type of the file provided.
@@ -321,3 +351,10 @@ pub struct ClosedCaptionFile<'a> {
     #[xml(text, cdata)]
     pub uri: std::borrow::Cow<'a, str>,
 }
+
+impl ClosedCaptionFile<'_> {
+    /// Returns the typed MIME type of the file.
+    pub fn mime(&self) -> Option<crate::MimeType> {
+        self.mime_type.as_deref().map(|s| s.parse().unwrap_or_else(|e| match e {}))
+    }
+}
@@ -0,0 +1,249 @@
+/// Identifies the document a tracking resource was taken from.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Origin {
+    /// The n-th [`Wrapper`](crate::Wrapper) of the chain, outermost first.
+    Wrapper(usize),
+    /// The terminal [`InLine`](crate::InLine) ad.
+    InLine,
+}
+
+/// A value together with the document it was taken from.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Sourced<T> {
+    pub origin: Origin,
+    pub value: T,
+}
+
+/// An [`InLine`](crate::InLine) ad with the tracking resources of every wrapper in its chain
+/// merged in, so the player can fire all of them from a single place.
+#[derive(PartialEq, Clone, Debug)]
+pub struct MergedAd<'a> {
+    /// The terminal [`InLine`](crate::InLine) ad.
+    pub in_line: crate::InLine<'a>,
+    /// The `<Error>` URIs of every level.
+    pub errors: Vec<Sourced<std::borrow::Cow<'a, str>>>,
+    /// The [`<Impression>`](crate::Impression) elements of every level.
+    pub impressions: Vec<Sourced<crate::Impression<'a>>>,
+    /// The [`<ViewableImpression>`](crate::ViewableImpression) elements of every level.
+    pub viewable_impressions: Vec<Sourced<crate::ViewableImpression<'a>>>,
+    /// The [`<Verification>`](crate::Verification) elements of every level.
+    pub verifications: Vec<Sourced<crate::Verification<'a>>>,
+    /// One entry per creative of the [`InLine`](crate::InLine) ad, in document order.
+    pub creatives: Vec<MergedCreative<'a>>,
+}
+
+/// An [`InLine`](crate::InLine) creative with the tracking resources of the matching wrapper
+/// creatives merged in.
+#[derive(PartialEq, Clone, Debug)]
+pub struct MergedCreative<'a> {
+    /// The [`InLine`](crate::InLine) creative.
+    pub creative: crate::Creative<'a>,
+    /// The [`<Tracking>`](crate::Tracking) elements of the [`Linear`](crate::Linear) element.
+    pub linear_trackings: Vec<Sourced<crate::Tracking<'a>>>,
+    /// The [`<ClickTracking>`](crate::ClickTracking) elements of the
+    /// [`VideoClicks`](crate::VideoClicks) element.
+    pub click_trackings: Vec<Sourced<crate::ClickTracking<'a>>>,
+    /// The [`<Tracking>`](crate::Tracking) elements of the [`NonLinearAds`](crate::NonLinearAds)
+    /// element.
+    pub non_linear_trackings: Vec<Sourced<crate::Tracking<'a>>>,
+    /// The [`<NonLinearClickTracking>`](crate::NonLinearClickTracking) elements of every
+    /// [`NonLinear`](crate::NonLinear) element.
+    pub non_linear_click_trackings: Vec<Sourced<crate::NonLinearClickTracking<'a>>>,
+}
+
+impl<'a> MergedAd<'a> {
+    /// Merges the tracking resources of `wrappers` (outermost first) into `in_line`.
+    ///
+    /// A wrapper creative applies to the inline creatives whose `adId` or
+    /// [`UniversalAdId`](crate::UniversalAdId) matches its `adId`, or, when it has no `adId`,
+    /// whose `sequence` matches its `sequence`. A wrapper creative carrying neither applies to
+    /// every inline creative.
+    pub fn new(wrappers: &[&crate::Wrapper<'a>], in_line: &crate::InLine<'a>) -> Self {
+        let levels = wrappers
+            .iter()
+            .enumerate()
+            .map(|(i, w)| Level {
+                origin: Origin::Wrapper(i),
+                errors: &w.errors,
+                impressions: &w.impressions,
+                viewable_impression: w.viewable_impression.as_ref(),
+                ad_verifications: w.ad_verifications.as_ref(),
+                creatives: w.creatives.as_ref().map(|c| &c.creatives[..]).unwrap_or_default(),
+            })
+            .chain(std::iter::once(Level {
+                origin: Origin::InLine,
+                errors: &in_line.errors,
+                impressions: &in_line.impressions,
+                viewable_impression: in_line.viewable_impression.as_ref(),
+                ad_verifications: in_line.ad_verifications.as_ref(),
+                creatives: &in_line.creatives.creatives,
+            }))
+            .collect::<Vec<_>>();
+
+        let mut merged = Self {
+            in_line: in_line.clone(),
+            errors: Vec::new(),
+            impressions: Vec::new(),
+            viewable_impressions: Vec::new(),
+            verifications: Vec::new(),
+            creatives: Vec::new(),
+        };
+
+        for level in &levels {
+            let origin = level.origin;
+            merged.errors.extend(level.errors.iter().map(|v| sourced(origin, v)));
+            merged.impressions.extend(level.impressions.iter().map(|v| sourced(origin, v)));
+            merged
+                .viewable_impressions
+                .extend(level.viewable_impression.map(|v| sourced(origin, v)));
+            if let Some(ad_verifications) = level.ad_verifications {
+                merged
+                    .verifications
+                    .extend(ad_verifications.verifications.iter().map(|v| sourced(origin, v)));
+            }
+        }
+
+        for creative in &in_line.creatives.creatives {
+            let mut merged_creative = MergedCreative {
+                creative: creative.clone(),
+                linear_trackings: Vec::new(),
+                click_trackings: Vec::new(),
+                non_linear_trackings: Vec::new(),
+                non_linear_click_trackings: Vec::new(),
+            };
+            for level in &levels {
+                let origin = level.origin;
+                let sources: Vec<&crate::Creative<'a>> = match origin {
+                    Origin::InLine => vec![creative],
+                    Origin::Wrapper(_) => {
+                        level.creatives.iter().filter(|c| matches(c, creative)).collect()
+                    }
+                };
+                for source in sources {
+                    merged_creative.extend(origin, source, creative);
+                }
+            }
+            merged.creatives.push(merged_creative);
+        }
+
+        merged
+    }
+}
+
+impl<'a> MergedCreative<'a> {
+    fn extend(&mut self, origin: Origin, source: &crate::Creative<'a>, target: &crate::Creative) {
+        if let (Some(linear), Some(_)) = (&source.linear, &target.linear) {
+            if let Some(ref tracking_events) = linear.tracking_events {
+                self.linear_trackings
+                    .extend(tracking_events.trackings.iter().map(|v| sourced(origin, v)));
+            }
+            if let Some(ref video_clicks) = linear.video_clicks {
+                self.click_trackings
+                    .extend(video_clicks.click_trackings.iter().map(|v| sourced(origin, v)));
+            }
+        }
+        if let (Some(non_linear_ads), Some(_)) = (&source.non_linear_ads, &target.non_linear_ads) {
+            if let Some(ref tracking_events) = non_linear_ads.tracking_events {
+                self.non_linear_trackings
+                    .extend(tracking_events.trackings.iter().map(|v| sourced(origin, v)));
+            }
+            for non_linear in &non_linear_ads.non_linears {
+                self.non_linear_click_trackings.extend(
+                    non_linear.non_linear_click_trackings.iter().map(|v| sourced(origin, v)),
+                );
+            }
+        }
+    }
+}
+
+impl<'a> crate::ResolvedAd<'a> {
+    /// Merges the tracking resources of the followed wrappers into the final inline ad. Returns
+    /// `None` when [`ResolvedAd::ad`](crate::ResolvedAd) holds no [`InLine`](crate::InLine).
+    pub fn merge(&self) -> Option<MergedAd<'a>> {
+        let wrappers =
+            self.wrappers.iter().filter_map(|ad| ad.wrapper.as_ref()).collect::<Vec<_>>();
+        self.ad.in_line.as_ref().map(|in_line| MergedAd::new(&wrappers, in_line))
+    }
+}
+
+struct Level<'b, 'a> {
+    origin: Origin,
+    errors: &'b [std::borrow::Cow<'a, str>],
+    impressions: &'b [crate::Impression<'a>],
+    viewable_impression: Option<&'b crate::ViewableImpression<'a>>,
+    ad_verifications: Option<&'b crate::AdVerifications<'a>>,
+    creatives: &'b [crate::Creative<'a>],
+}
+
+fn sourced<T: Clone>(origin: Origin, value: &T) -> Sourced<T> {
+    Sourced { origin, value: value.clone() }
+}
+
+fn matches(wrapper: &crate::Creative, in_line: &crate::Creative) -> bool {
+    if let Some(ref ad_id) = wrapper.ad_id {
+        return in_line.ad_id.as_ref() == Some(ad_id)
+            || in_line.universal_ad_id.iter().any(|id| id.id == *ad_id);
+    }
+    if let Some(sequence) = wrapper.sequence {
+        return in_line.sequence == Some(sequence);
+    }
+    true
+}
+
+#[cfg(test)]
+#[test]
+fn test_merge_wrapper_tracking() {
+    const WRAPPER: &str = r#"<VAST version="4.2"><Ad id="w"><Wrapper>
+        <AdSystem>wrapper</AdSystem>
+        <Error><![CDATA[https://wrapper.com/error]]></Error>
+        <Impression><![CDATA[https://wrapper.com/impression]]></Impression>
+        <Creatives>
+          <Creative adId="8465"><Linear><TrackingEvents>
+            <Tracking event="start"><![CDATA[https://wrapper.com/start]]></Tracking>
+          </TrackingEvents><VideoClicks>
+            <ClickTracking><![CDATA[https://wrapper.com/click]]></ClickTracking>
+          </VideoClicks></Linear></Creative>
+          <Creative adId="unknown"><Linear><TrackingEvents>
+            <Tracking event="start"><![CDATA[https://wrapper.com/other]]></Tracking>
+          </TrackingEvents></Linear></Creative>
+        </Creatives>
+        <VASTAdTagURI>inline</VASTAdTagURI>
+      </Wrapper></Ad></VAST>"#;
+
+    let mut wrapper = crate::from_str::<crate::Vast>(WRAPPER).unwrap();
+    let mut in_line = crate::from_str::<crate::Vast>(include_str!(
+        "../tests/data/v4_2/Inline_Linear_Tag-test.xml"
+    ))
+    .unwrap();
+    let resolved =
+        crate::ResolvedAd { wrappers: wrapper.ads.split_off(0), ad: in_line.ads.remove(0) };
+    let merged = resolved.merge().unwrap();
+
+    let impressions =
+        merged.impressions.iter().map(|i| (i.origin, &*i.value.uri)).collect::<Vec<_>>();
+    assert_eq!(
+        impressions,
+        [
+            (Origin::Wrapper(0), "https://wrapper.com/impression"),
+            (Origin::InLine, "https://example.com/track/impression")
+        ]
+    );
+    assert_eq!(merged.errors.len(), 2);
+
+    let creative = &merged.creatives[0];
+    let starts = creative
+        .linear_trackings
+        .iter()
+        .filter(|t| t.value.event == crate::TrackingEvent::Start)
+        .map(|t| (t.origin, &*t.value.uri))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        starts,
+        [
+            (Origin::Wrapper(0), "https://wrapper.com/start"),
+            (Origin::InLine, "https://example.com/tracking/start")
+        ]
+    );
+    assert_eq!(creative.click_trackings.len(), 1);
+    assert_eq!(creative.click_trackings[0].origin, Origin::Wrapper(0));
+}
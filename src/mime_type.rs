@@ -0,0 +1,334 @@
+/// Represents the MIME type of a media file or creative, e.g. the `type` of a
+/// [`<MediaFile>`](crate::MediaFile) or the `creativeType` of a
+/// [`<StaticResource>`](crate::StaticResource).
+///
+/// The parameters of the type are ignored and types are matched case-insensitively, so both
+/// `application/x-mpegURL` and `application/vnd.apple.mpegurl` are [`Hls`](Self::Hls).
+///
+/// ```
+/// use vast4::MimeType;
+///
+/// assert_eq!("video/MP4; codecs=\"avc1.42E01E\"".parse(), Ok(MimeType::Mp4));
+/// assert_eq!("application/x-mpegURL".parse(), Ok(MimeType::Hls));
+/// assert_eq!("video/x-flv".parse(), Ok(MimeType::Other("video/x-flv".to_owned())));
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub enum MimeType {
+    /// `video/mp4`.
+    Mp4,
+    /// `video/webm`.
+    WebM,
+    /// `video/ogg`.
+    Ogg,
+    /// `video/quicktime`.
+    QuickTime,
+    /// `video/3gpp`.
+    ThreeGpp,
+    /// `application/x-mpegURL` or `application/vnd.apple.mpegurl`, an HLS m3u8 playlist.
+    Hls,
+    /// `application/dash+xml`, a DASH mpd manifest.
+    Dash,
+    /// `audio/mp4`.
+    AudioMp4,
+    /// `audio/mpeg`.
+    AudioMpeg,
+    /// `audio/aac`.
+    AudioAac,
+    /// `audio/ogg`.
+    AudioOgg,
+    /// `audio/webm`.
+    AudioWebM,
+    /// `audio/wav`.
+    AudioWav,
+    /// `text/vtt`, a WebVTT closed caption file.
+    WebVtt,
+    /// `application/ttml+xml`, a TTML closed caption file.
+    Ttml,
+    /// `image/png`.
+    Png,
+    /// `image/jpeg`.
+    Jpeg,
+    /// `image/gif`.
+    Gif,
+    /// `image/webp`.
+    WebP,
+    /// `image/svg+xml`.
+    Svg,
+    /// `application/javascript` or `text/javascript`, e.g. of a
+    /// [`<StaticResource>`](crate::StaticResource).
+    JavaScript,
+    /// Any other MIME type, without parameters and in lowercase.
+    Other(String),
+}
+
+impl MimeType {
+    /// Returns whether the type is a video container.
+    pub fn is_video(&self) -> bool {
+        matches!(self, Self::Mp4 | Self::WebM | Self::Ogg | Self::QuickTime | Self::ThreeGpp)
+            || matches!(self, Self::Other(s) if s.starts_with("video/"))
+    }
+
+    /// Returns whether the type is an audio container.
+    pub fn is_audio(&self) -> bool {
+        matches!(
+            self,
+            Self::AudioMp4
+                | Self::AudioMpeg
+                | Self::AudioAac
+                | Self::AudioOgg
+                | Self::AudioWebM
+                | Self::AudioWav
+        ) || matches!(self, Self::Other(s) if s.starts_with("audio/"))
+    }
+
+    /// Returns whether the type is an image.
+    pub fn is_image(&self) -> bool {
+        matches!(self, Self::Png | Self::Jpeg | Self::Gif | Self::WebP | Self::Svg)
+            || matches!(self, Self::Other(s) if s.starts_with("image/"))
+    }
+
+    /// Returns whether the type is an adaptive streaming manifest, i.e. HLS or DASH.
+    pub fn is_streaming(&self) -> bool {
+        matches!(self, Self::Hls | Self::Dash)
+    }
+
+    /// Returns the MIME type, e.g. `video/mp4`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Mp4 => "video/mp4",
+            Self::WebM => "video/webm",
+            Self::Ogg => "video/ogg",
+            Self::QuickTime => "video/quicktime",
+            Self::ThreeGpp => "video/3gpp",
+            Self::Hls => "application/x-mpegURL",
+            Self::Dash => "application/dash+xml",
+            Self::AudioMp4 => "audio/mp4",
+            Self::AudioMpeg => "audio/mpeg",
+            Self::AudioAac => "audio/aac",
+            Self::AudioOgg => "audio/ogg",
+            Self::AudioWebM => "audio/webm",
+            Self::AudioWav => "audio/wav",
+            Self::WebVtt => "text/vtt",
+            Self::Ttml => "application/ttml+xml",
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Gif => "image/gif",
+            Self::WebP => "image/webp",
+            Self::Svg => "image/svg+xml",
+            Self::JavaScript => "application/javascript",
+            Self::Other(s) => s,
+        }
+    }
+}
+
+impl std::str::FromStr for MimeType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+        Ok(match s.as_str() {
+            "video/mp4" => Self::Mp4,
+            "video/webm" => Self::WebM,
+            "video/ogg" => Self::Ogg,
+            "video/quicktime" => Self::QuickTime,
+            "video/3gpp" => Self::ThreeGpp,
+            "application/x-mpegurl" | "application/vnd.apple.mpegurl" | "audio/mpegurl" => {
+                Self::Hls
+            }
+            "application/dash+xml" => Self::Dash,
+            "audio/mp4" => Self::AudioMp4,
+            "audio/mpeg" | "audio/mp3" => Self::AudioMpeg,
+            "audio/aac" => Self::AudioAac,
+            "audio/ogg" => Self::AudioOgg,
+            "audio/webm" => Self::AudioWebM,
+            "audio/wav" | "audio/x-wav" | "audio/wave" => Self::AudioWav,
+            "text/vtt" => Self::WebVtt,
+            "application/ttml+xml" => Self::Ttml,
+            "image/png" => Self::Png,
+            "image/jpeg" | "image/jpg" => Self::Jpeg,
+            "image/gif" => Self::Gif,
+            "image/webp" => Self::WebP,
+            "image/svg+xml" => Self::Svg,
+            "application/javascript" | "application/x-javascript" | "text/javascript" => {
+                Self::JavaScript
+            }
+            _ => Self::Other(s),
+        })
+    }
+}
+
+impl std::fmt::Display for MimeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Represents a codec of a media file as specified by
+/// [RFC 6381](https://datatracker.ietf.org/doc/html/rfc6381), e.g. `avc1.42E01E` or `mp4a.40.2`.
+///
+/// The profile and level are parsed for the AVC (`avc1`, `avc3`), HEVC (`hev1`, `hvc1`), AAC
+/// (`mp4a`), VP9 (`vp09`) and AV1 (`av01`) codec families. The common names of these codecs,
+/// e.g. `H.264` as used by the IAB sample tags, are parsed as their family without parameters.
+///
+/// ```
+/// use vast4::Codec;
+///
+/// let codec: Codec = "avc1.42E01E".parse().unwrap();
+/// assert_eq!((codec.family.as_str(), codec.profile, codec.level), ("avc1", Some(66), Some(30)));
+/// let codec: Codec = "mp4a.40.2".parse().unwrap();
+/// assert_eq!((codec.family.as_str(), codec.profile, codec.level), ("mp4a", Some(2), None));
+/// let codec: Codec = "H.264".parse().unwrap();
+/// assert_eq!((codec.family.as_str(), codec.profile, codec.level), ("avc1", None, None));
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct Codec {
+    /// The codec family, e.g. `avc1`.
+    pub family: String,
+    /// The parameters following the family, e.g. `["42E01E"]` for `avc1.42E01E`.
+    pub parameters: Vec<String>,
+    /// The profile, e.g. `66` (Baseline) for `avc1.42E01E` or the audio object type `2` (AAC-LC)
+    /// for `mp4a.40.2`.
+    pub profile: Option<u32>,
+    /// The level, e.g. `30` (3.0) for `avc1.42E01E`.
+    pub level: Option<u32>,
+}
+
+impl Codec {
+    /// Parses a comma separated list of codecs, e.g. `avc1.42E01E, mp4a.40.2`.
+    pub fn parse_list(s: &str) -> Result<Vec<Self>, crate::VastParseError> {
+        s.split(',').map(|codec| codec.trim().parse()).collect()
+    }
+
+    /// Returns whether a device supporting the `supported` codec can play this codec.
+    ///
+    /// A codec without parameters supports the whole family, and one is supported by any codec
+    /// of its family. Otherwise the profile must be the same and the level must not be higher,
+    /// or, when neither is known, the parameters must be the same.
+    pub fn is_supported_by(&self, supported: &Self) -> bool {
+        if !self.family.eq_ignore_ascii_case(&supported.family) {
+            return false;
+        }
+        if supported.parameters.is_empty() || self.parameters.is_empty() {
+            return true;
+        }
+        match (supported.profile, supported.level) {
+            (None, None) => {
+                self.parameters.len() == supported.parameters.len()
+                    && self
+                        .parameters
+                        .iter()
+                        .zip(&supported.parameters)
+                        .all(|(a, b)| a.eq_ignore_ascii_case(b))
+            }
+            (profile, level) => {
+                profile.is_none_or(|p| self.profile == Some(p))
+                    && level.is_none_or(|l| self.level.is_some_and(|level| level <= l))
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for Codec {
+    type Err = crate::VastParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let alias = match s.to_ascii_lowercase().as_str() {
+            "h.264" | "h264" | "avc" => Some("avc1"),
+            "h.265" | "h265" | "hevc" => Some("hvc1"),
+            "vp9" => Some("vp09"),
+            "av1" => Some("av01"),
+            "aac" => Some("mp4a"),
+            _ => None,
+        };
+        if let Some(family) = alias {
+            let family = family.to_owned();
+            return Ok(Self { family, parameters: Vec::new(), profile: None, level: None });
+        }
+
+        let mut split = s.split('.');
+        let family = split.next().unwrap_or_default();
+        let parameters: Vec<String> = split.map(str::to_owned).collect();
+        if family.is_empty()
+            || parameters.iter().any(|p| p.is_empty())
+            || !s.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'.' || b == b'-')
+        {
+            return Err(crate::VastParseError::new(format!("codec parsing error: '{s}'")));
+        }
+
+        let hex = |s: &str| u32::from_str_radix(s, 16).ok();
+        let dec = |s: &str| s.parse::<u32>().ok();
+        let param = |i: usize| parameters.get(i).map(String::as_str);
+        let (profile, level) = match family.to_ascii_lowercase().as_str() {
+            // profile_idc, constraint flags and level_idc as hexadecimal bytes
+            "avc1" | "avc3" => match param(0) {
+                Some(p) if p.len() == 6 => (hex(&p[..2]), hex(&p[4..])),
+                _ => (None, None),
+            },
+            // the general profile, optionally after its space, and the level after its tier
+            "hev1" | "hvc1" => (
+                param(0).and_then(|p| dec(p.trim_start_matches(|c: char| c.is_ascii_alphabetic()))),
+                param(2).and_then(|p| dec(p.get(1..)?)),
+            ),
+            // the object type indication, then the audio object type
+            "mp4a" => (param(1).and_then(dec), None),
+            "vp09" => (param(0).and_then(dec), param(1).and_then(dec)),
+            // the level is followed by its tier
+            "av01" => (
+                param(0).and_then(dec),
+                param(1).and_then(|p| dec(p.trim_end_matches(|c: char| c.is_ascii_alphabetic()))),
+            ),
+            _ => (None, None),
+        };
+
+        Ok(Self { family: family.to_owned(), parameters, profile, level })
+    }
+}
+
+impl std::fmt::Display for Codec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.family)?;
+        for parameter in &self.parameters {
+            write!(f, ".{parameter}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_codec() {
+    let codec = |s: &str| s.parse::<Codec>().unwrap();
+    let profile_level = |s: &str| (codec(s).profile, codec(s).level);
+
+    assert_eq!(profile_level("avc1.640028"), (Some(100), Some(40)));
+    assert_eq!(profile_level("hev1.1.6.L93.B0"), (Some(1), Some(93)));
+    assert_eq!(profile_level("hvc1.A2.4.H120.90"), (Some(2), Some(120)));
+    assert_eq!(profile_level("vp09.00.10.08"), (Some(0), Some(10)));
+    assert_eq!(profile_level("av01.0.04M.08"), (Some(0), Some(4)));
+    assert_eq!(profile_level("vp9"), (None, None));
+    assert_eq!(codec("H.264"), codec("avc1"));
+    assert_eq!(codec("HEVC").to_string(), "hvc1");
+    assert_eq!(codec("vp9"), codec("vp09"));
+    assert!("".parse::<Codec>().is_err());
+    assert!("avc1..42".parse::<Codec>().is_err());
+    assert_eq!(
+        Codec::parse_list("avc1.42E01E, mp4a.40.2").unwrap(),
+        [codec("avc1.42E01E"), codec("mp4a.40.2")]
+    );
+
+    assert!(codec("avc1.42E01E").is_supported_by(&codec("avc1")));
+    assert!(codec("avc1.42E01E").is_supported_by(&codec("AVC1.42E028")));
+    assert!(!codec("avc1.42E028").is_supported_by(&codec("avc1.42E01E")));
+    assert!(!codec("avc1.4D401E").is_supported_by(&codec("avc1.42E01E")));
+    assert!(!codec("avc1.42E01E").is_supported_by(&codec("hev1")));
+    assert!(codec("H.264").is_supported_by(&codec("avc1")));
+    assert!(codec("H.264").is_supported_by(&codec("avc1.42E01E")));
+    assert!(codec("avc1.42E01E").is_supported_by(&codec("h264")));
+    assert!(!codec("H.264").is_supported_by(&codec("H.265")));
+    assert!(codec("vp8").is_supported_by(&codec("VP8")));
+
+    assert!(MimeType::Mp4.is_video() && !MimeType::Mp4.is_audio());
+    assert!("audio/x-wav".parse::<MimeType>().unwrap().is_audio());
+    assert!("application/vnd.apple.mpegurl".parse::<MimeType>().unwrap().is_streaming());
+    assert!("image/JPG".parse::<MimeType>().unwrap().is_image());
+}
@@ -11,6 +11,7 @@
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "NonLinearAds", strict(unknown_attribute, unknown_element))]
 pub struct NonLinearAds<'a> {
     /// The container for zero or one `<TrackingEvents>`(crate::TrackingEvents) element.
@@ -59,6 +60,7 @@ pub struct NonLinearAds<'a> {
 /// </xs:complexType>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "NonLinear", strict(unknown_attribute, unknown_element))]
 pub struct NonLinear<'a> {
     /// InLine: An optional identifier for the creative.
@@ -144,6 +146,7 @@ pub struct NonLinear<'a> {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "NonLinearClickTracking", strict(unknown_attribute, unknown_element))]
 pub struct NonLinearClickTracking<'a> {
     /// An id provided by the ad server to track the click in reports.
@@ -0,0 +1,303 @@
+/// Converts a value borrowing from the input into one owning all of its data, e.g. to keep a
+/// parsed [`Vast`](crate::Vast) after the input is dropped or to send it to another thread.
+///
+/// Every type of the model implements this trait, and has an inherent `into_owned` method doing
+/// the same.
+pub trait IntoOwned {
+    type Owned: 'static;
+
+    fn into_owned(self) -> Self::Owned;
+}
+
+/// A type of the model that can be read into owned data with [`from_string`] or
+/// [`from_reader`].
+pub trait ReadOwned: Sized + 'static {
+    /// The type borrowing from the input, e.g. `Vast<'a>` for `Vast<'static>`.
+    type Borrowed<'a>: hard_xml::XmlRead<'a> + IntoOwned<Owned = Self>;
+}
+
+/// Reads a value owning all of its data from `s`, e.g. `from_string::<Vast>(body)`.
+pub fn from_string<T: ReadOwned>(s: String) -> crate::Result<T> {
+    Ok(crate::from_str::<T::Borrowed<'_>>(&s)?.into_owned())
+}
+
+/// Reads a value owning all of its data from `reader`, see [`from_string`].
+///
+/// An I/O error, or input which is not UTF-8, is returned as an [`Error`](crate::Error) on line 1,
+/// column 1.
+pub fn from_reader<T: ReadOwned>(mut reader: impl std::io::Read) -> crate::Result<T> {
+    let mut s = String::new();
+    reader.read_to_string(&mut s).map_err(crate::Error::io)?;
+    from_string(s)
+}
+
+impl IntoOwned for std::borrow::Cow<'_, str> {
+    type Owned = std::borrow::Cow<'static, str>;
+
+    fn into_owned(self) -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Owned(self.into_owned())
+    }
+}
+
+impl<T: IntoOwned> IntoOwned for Option<T> {
+    type Owned = Option<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        self.map(IntoOwned::into_owned)
+    }
+}
+
+impl<T: IntoOwned> IntoOwned for Vec<T> {
+    type Owned = Vec<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        self.into_iter().map(IntoOwned::into_owned).collect()
+    }
+}
+
+impl<T: IntoOwned> IntoOwned for crate::Sourced<T> {
+    type Owned = crate::Sourced<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        crate::Sourced { origin: self.origin, value: self.value.into_owned() }
+    }
+}
+
+impl IntoOwned for crate::AnyVast<'_> {
+    type Owned = crate::AnyVast<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        match self {
+            Self::V3(vast) => crate::AnyVast::V3(vast.into_owned()),
+            Self::V4(vast) => crate::AnyVast::V4(vast.into_owned()),
+        }
+    }
+}
+
+impl crate::AnyVast<'_> {
+    /// Converts into a value owning all of its data.
+    pub fn into_owned(self) -> crate::AnyVast<'static> {
+        IntoOwned::into_owned(self)
+    }
+}
+
+// types without borrowed data
+macro_rules! impl_static {
+    ($($($ty:ident)::+),* $(,)?) => {
+        $(
+            impl IntoOwned for $($ty)::+ {
+                type Owned = Self;
+
+                fn into_owned(self) -> Self {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_static!(
+    bool,
+    i32,
+    u32,
+    f32,
+    f64,
+    String,
+    crate::Origin,
+    crate::Duration,
+    crate::AdType,
+    crate::CompanionRequirement,
+    crate::RenderingMode,
+    crate::CreativeExtensions,
+    crate::CreativeExtension,
+    crate::Extensions,
+    crate::Extension,
+    crate::XPosition,
+    crate::YPosition,
+    crate::PriceModel,
+    crate::DeliveryProtocol,
+    crate::TrackingEvent,
+    crate::v3::TrackingEvent,
+    crate::Offset,
+    crate::VastVersion,
+    crate::vmap::CustomAdData,
+    crate::vmap::TrackingEvent,
+    crate::vmap::Extensions,
+    crate::vmap::Extension,
+    crate::vmap::TimeOffset,
+    crate::vmap::BreakType,
+    crate::vmap::BreakTypes,
+);
+
+macro_rules! impl_read_owned_static {
+    ($($($ty:ident)::+),* $(,)?) => {
+        $(
+            impl ReadOwned for $($ty)::+ {
+                type Borrowed<'a> = Self;
+            }
+        )*
+    };
+}
+
+impl_read_owned_static!(
+    crate::Duration,
+    crate::CreativeExtensions,
+    crate::CreativeExtension,
+    crate::Extensions,
+    crate::Extension,
+    crate::vmap::CustomAdData,
+    crate::vmap::Extensions,
+    crate::vmap::Extension,
+);
+
+// types borrowing from the input, with all of their fields
+macro_rules! impl_into_owned {
+    ($($($ty:ident)::+ { $($field:ident),* $(,)? })*) => {
+        $(
+            impl $($ty)::+<'_> {
+                /// Converts into a value owning all of its data.
+                pub fn into_owned(self) -> $($ty)::+<'static> {
+                    $($ty)::+ { $($field: IntoOwned::into_owned(self.$field)),* }
+                }
+            }
+
+            impl IntoOwned for $($ty)::+<'_> {
+                type Owned = $($ty)::+<'static>;
+
+                fn into_owned(self) -> Self::Owned {
+                    $($ty)::+::into_owned(self)
+                }
+            }
+        )*
+    };
+}
+
+// types borrowing from the input which are read from XML
+macro_rules! impl_read_owned {
+    ($($($ty:ident)::+ { $($field:ident),* $(,)? })*) => {
+        impl_into_owned!($($($ty)::+ { $($field),* })*);
+
+        $(
+            impl ReadOwned for $($ty)::+<'static> {
+                type Borrowed<'a> = $($ty)::+<'a>;
+            }
+        )*
+    };
+}
+
+impl_read_owned! {
+    crate::Ad { id, sequence, conditional_ad, ad_type, in_line, wrapper }
+
+    crate::VideoClicks { click_trackings, click_through, custom_clicks }
+    crate::ClickThrough { id, uri }
+    crate::ClickTracking { id, uri }
+    crate::CustomClick { id, uri }
+
+    crate::CompanionAds { required, companions }
+    crate::Companion { id, width, height, asset_width, asset_height, expanded_width, expanded_height, api_framework, ad_slot_id, pxratio, rendering_mode, html_resources, iframe_resources, static_resources, ad_parameters, alt_text, companion_click_through, companion_click_trackings, creative_extensiosn, tracking_events }
+    crate::CompanionClickTracking { id, uri }
+
+    crate::Creatives { creatives }
+    crate::Creative { sequence, api_framework, id, ad_id, companion_ads, creative_extensions, linear, non_linear_ads, universal_ad_id }
+    crate::UniversalAdId { id_registry, id }
+
+    crate::StaticResource { creative_type, uri }
+
+    crate::Icons { icons }
+    crate::Icon { program, width, height, x_position, y_position, duration, offset, api_framework, pxratio, html_resources, iframe_resources, static_resources, icon_clicks, icon_view_trackings }
+    crate::IconClicks { icon_click_fallback_images, icon_click_through, icon_click_trackings }
+    crate::IconClickTracking { id, uri }
+    crate::IconClickFallbackImages { icon_click_fallback_images }
+    crate::IconClickFallbackImage { height, width, alt_text, static_resource }
+
+    crate::InLine { ad_system, errors, extensions, impressions, pricing, viewable_impression, ad_serving_id, ad_title, ad_verifications, advertiser, categories, creatives, description, expires, surveys }
+    crate::AdSystem { version, name }
+    crate::Impression { id, uri }
+    crate::Category { authority, code }
+    crate::Pricing { model, currency, price }
+    crate::Survey { mime_type, uri }
+
+    crate::Linear { skipoffset, icons, tracking_events, ad_parameters, duration, media_files, video_clicks }
+    crate::AdParameters { xml_encoded, metadata }
+
+    crate::MediaFiles { closed_caption_files, media_files, mezzanines, interactive_creative_files }
+    crate::MediaFile { id, delivery, mime_type, width, height, codec, bitrate, min_bitrate, max_bitrate, scalable, maintain_aspect_ratio, file_size, media_type, api_framework, uri }
+    crate::Mezzanine { id, delivery, mime_type, width, height, codec, file_size, media_type, uri }
+    crate::InteractiveCreativeFile { mime_type, api_framework, variable_duration, uri }
+    crate::ClosedCaptionFiles { closed_caption_files }
+    crate::ClosedCaptionFile { mime_type, language, uri }
+
+    crate::NonLinearAds { tracking_events, non_linears }
+    crate::NonLinear { id, width, height, expanded_width, expanded_height, scalable, maintain_aspect_ratio, min_suggested_duration, api_framework, html_resources, iframe_resources, static_resources, ad_parameters, non_linear_click_through, non_linear_click_trackings }
+    crate::NonLinearClickTracking { id, uri }
+
+    crate::TrackingEvents { trackings }
+    crate::Tracking { event, offset, uri }
+
+    crate::v3::Vast { version, ads, errors }
+    crate::v3::Ad { id, sequence, in_line, wrapper }
+    crate::v3::InLine { ad_system, ad_title, description, advertiser, pricing, survey, errors, impressions, creatives, extensions }
+    crate::v3::Wrapper { follow_additional_wrappers, allow_multiple_ads, fallback_on_no_ad, ad_system, vast_ad_tag_uri, errors, impressions, creatives, extensions }
+    crate::v3::Creatives { creatives }
+    crate::v3::Creative { id, sequence, ad_id, api_framework, creative_extensions, linear, companion_ads, non_linear_ads }
+    crate::v3::CompanionAds { required, companions }
+    crate::v3::Companion { id, width, height, asset_width, asset_height, expanded_width, expanded_height, api_framework, ad_slot_id, html_resources, iframe_resources, static_resources, creative_extensions, tracking_events, companion_click_through, companion_click_trackings, alt_text, ad_parameters }
+    crate::v3::CompanionClickTracking { id, uri }
+    crate::v3::Linear { skipoffset, icons, tracking_events, ad_parameters, duration, media_files, video_clicks }
+    crate::v3::NonLinearAds { tracking_events, non_linears }
+    crate::v3::TrackingEvents { trackings }
+    crate::v3::Tracking { event, offset, uri }
+
+    crate::Vast { version, ads, errors }
+
+    crate::AdVerifications { verifications }
+    crate::Verification { vendor, executable_resources, javascript_resources, tracking_events, verification_parameters }
+    crate::JavaScriptResource { api_framework, browser_optional, uri }
+    crate::ExecutableResource { api_framework, resource_type, uri }
+    crate::VerificationTrackingEvents { trackings }
+    crate::VerificationTracking { event, uri }
+
+    crate::ViewableImpression { id, viewables, not_viewables, view_undetermineds }
+
+    crate::vmap::Vmap { xmlns, version, ad_breaks, extensions }
+    crate::vmap::AdBreak { time_offset, break_type, break_id, repeat_after, ad_source, tracking_events, extensions }
+    crate::vmap::AdSource { id, allow_multiple_ads, follow_redirects, vast_ad_data, custom_ad_data, ad_tag_uri }
+    crate::vmap::VastAdData { vast }
+    crate::vmap::AdTagUri { template_type, uri }
+    crate::vmap::TrackingEvents { trackings }
+    crate::vmap::Tracking { event, uri }
+
+    crate::Wrapper { follow_additional_wrappers, allow_multiple_ads, fallback_on_no_ad, ad_system, errors, extensions, impressions, pricing, viewable_impression, ad_verifications, blocked_ad_categories, creatives, vast_ad_tag_uri }
+    crate::BlockedAdCategories { authority, codes }
+}
+
+impl_into_owned! {
+    crate::MergedAd { in_line, errors, impressions, viewable_impressions, verifications, creatives }
+    crate::MergedCreative { creative, linear_trackings, click_trackings, non_linear_trackings, non_linear_click_trackings }
+
+    crate::ResolvedAd { wrappers, ad }
+}
+
+#[cfg(test)]
+#[test]
+fn test_into_owned() {
+    fn assert_static<T: Send + Sync + 'static>(_: &T) {}
+
+    let xml = include_str!("../tests/data/v4_2/Inline_Linear_Tag-test.xml").to_owned();
+    let vast = crate::from_str::<crate::Vast>(&xml).unwrap();
+    let owned = vast.clone().into_owned();
+    assert_eq!(owned, vast);
+    drop(vast);
+    drop(xml);
+    assert_static(&owned);
+
+    let xml = include_str!("../tests/data/v4_2/Inline_Linear_Tag-test.xml");
+    let vast = from_string::<crate::Vast>(xml.to_owned()).unwrap();
+    assert_eq!(vast, owned);
+    let vast = from_reader::<crate::Vast>(xml.as_bytes()).unwrap();
+    assert_eq!(vast, owned);
+
+    let error = from_reader::<crate::Vast>(&b"<VAST version=\"4.2\">\xff</VAST>"[..]).unwrap_err();
+    assert!(matches!(error.xml_error(), hard_xml::XmlError::IO(_)));
+    assert!(from_string::<crate::vmap::Vmap>(xml.to_owned()).is_err());
+}
@@ -0,0 +1,180 @@
+/// The [`Ad`](crate::Ad)s of a document split into an ad pod and an ad buffet.
+///
+/// The ads with a `sequence` attribute form the pod and are played in sequence order. The
+/// stand-alone ads without one form the buffet: they are played in place of pod ads that fail,
+/// or on their own when there is no pod.
+///
+/// ```
+/// let ad = |id: &'static str, sequence| vast4::Ad { id: Some(id.into()), sequence, ..Default::default() };
+/// let vast = vast4::Vast {
+///     ads: vec![ad("standalone", None), ad("second", Some(2)), ad("first", Some(1))],
+///     ..Default::default()
+/// };
+///
+/// let pod = vast4::AdPod::new(&vast);
+/// let mut playback = pod.playback();
+/// assert_eq!(playback.next().unwrap().id.as_deref(), Some("first"));
+/// // the second ad fails to play, the stand-alone ad is played instead
+/// assert_eq!(playback.next().unwrap().id.as_deref(), Some("second"));
+/// assert_eq!(playback.failed().unwrap().id.as_deref(), Some("standalone"));
+/// assert_eq!(playback.next(), None);
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+pub struct AdPod<'b, 'a> {
+    /// The ads with a `sequence` attribute, ordered by it. Ads with the same sequence are kept in
+    /// document order.
+    pub ads: Vec<&'b crate::Ad<'a>>,
+    /// The stand-alone ads, in document order.
+    pub buffet: Vec<&'b crate::Ad<'a>>,
+}
+
+impl<'b, 'a> AdPod<'b, 'a> {
+    /// Splits the ads of `vast`.
+    pub fn new(vast: &'b crate::Vast<'a>) -> Self {
+        let (mut ads, buffet): (Vec<_>, Vec<_>) =
+            vast.ads.iter().partition(|ad| ad.sequence.is_some());
+        ads.sort_by_key(|ad| ad.sequence);
+        Self { ads, buffet }
+    }
+
+    /// Splits the ads of `vast`, the response to the request of `wrapper`.
+    ///
+    /// Unless [`Wrapper::allow_multiple_ads`](crate::Wrapper::allow_multiple_ads) is `true`, the
+    /// pod is dropped and only the first stand-alone ad is kept.
+    pub fn for_wrapper(vast: &'b crate::Vast<'a>, wrapper: &crate::Wrapper) -> Self {
+        let mut pod = Self::new(vast);
+        if !wrapper.allow_multiple_ads.unwrap_or(false) {
+            pod.ads.clear();
+            pod.buffet.truncate(1);
+        }
+        pod
+    }
+
+    /// Returns whether there is no ad at all.
+    pub fn is_empty(&self) -> bool {
+        self.ads.is_empty() && self.buffet.is_empty()
+    }
+
+    /// Returns the total duration of the pod, i.e. of the [`Linear`](crate::Linear) creatives of
+    /// its [`InLine`](crate::InLine) ads. Creatives without a duration are not counted.
+    pub fn duration(&self) -> crate::Duration {
+        let duration: std::time::Duration = self
+            .ads
+            .iter()
+            .filter_map(|ad| ad.in_line.as_ref())
+            .flat_map(|in_line| &in_line.creatives.creatives)
+            .filter_map(|creative| creative.linear.as_ref()?.duration.clone())
+            .map(std::time::Duration::from)
+            .sum();
+        duration.into()
+    }
+
+    /// Returns the ads to play in order, see [`PodPlayback`].
+    pub fn playback(&self) -> PodPlayback<'_, 'b, 'a> {
+        PodPlayback { pod: self, next: 0, buffet: 0 }
+    }
+}
+
+/// The playback of an [`AdPod`].
+///
+/// It yields the pod ads in order, or the first stand-alone ad when there is no pod. When an ad
+/// fails to play, [`failed`](Self::failed) returns the next unused stand-alone ad to play in its
+/// place.
+#[derive(Clone, Debug)]
+pub struct PodPlayback<'p, 'b, 'a> {
+    pod: &'p AdPod<'b, 'a>,
+    next: usize,
+    buffet: usize,
+}
+
+impl<'b, 'a> PodPlayback<'_, 'b, 'a> {
+    /// Returns the stand-alone ad to play in place of the ad that failed, if any is left.
+    pub fn failed(&mut self) -> Option<&'b crate::Ad<'a>> {
+        let ad = self.pod.buffet.get(self.buffet)?;
+        self.buffet += 1;
+        Some(ad)
+    }
+}
+
+impl<'b, 'a> Iterator for PodPlayback<'_, 'b, 'a> {
+    type Item = &'b crate::Ad<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pod.ads.is_empty() {
+            // without a pod, a single stand-alone ad is played
+            return if self.next == 0 {
+                self.next = 1;
+                self.failed()
+            } else {
+                None
+            };
+        }
+        let ad = self.pod.ads.get(self.next)?;
+        self.next += 1;
+        Some(ad)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_ad_pod() {
+    fn ad(id: &'static str, sequence: Option<i32>, durations: &[u64]) -> crate::Ad<'static> {
+        let creatives = durations
+            .iter()
+            .map(|&secs| crate::Creative {
+                linear: Some(crate::Linear {
+                    duration: Some(crate::Duration::new(0, 0, secs, 500)),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .collect();
+        crate::Ad {
+            id: Some(id.into()),
+            sequence,
+            in_line: Some(crate::InLine {
+                creatives: crate::Creatives { creatives },
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+    fn ids<'b>(ads: impl IntoIterator<Item = &'b &'b crate::Ad<'b>>) -> Vec<&'b str> {
+        ads.into_iter().map(|ad| ad.id.as_deref().unwrap()).collect()
+    }
+
+    let mut wrapper = crate::Ad { id: Some("2".into()), sequence: Some(1), ..Default::default() };
+    wrapper.wrapper = Some(crate::Wrapper { allow_multiple_ads: Some(true), ..Default::default() });
+    let vast = crate::Vast {
+        ads: vec![
+            ad("a", None, &[15]),
+            ad("3", Some(3), &[30]),
+            ad("b", None, &[]),
+            ad("1", Some(1), &[10, 5]),
+            wrapper,
+        ],
+        ..Default::default()
+    };
+
+    let pod = AdPod::new(&vast);
+    assert_eq!(ids(&pod.ads), ["1", "2", "3"]);
+    assert_eq!(ids(&pod.buffet), ["a", "b"]);
+    assert_eq!(pod.duration(), crate::Duration::new(0, 0, 46, 500));
+
+    let mut playback = pod.playback();
+    assert_eq!(playback.next().unwrap().id.as_deref(), Some("1"));
+    assert_eq!(playback.failed().unwrap().id.as_deref(), Some("a"));
+    assert_eq!(playback.next().unwrap().id.as_deref(), Some("2"));
+    assert_eq!(playback.failed().unwrap().id.as_deref(), Some("b"));
+    assert_eq!(playback.next().unwrap().id.as_deref(), Some("3"));
+    assert_eq!(playback.failed(), None);
+    assert_eq!(playback.next(), None);
+
+    let wrapper = vast.ads[4].wrapper.as_ref().unwrap();
+    assert_eq!(AdPod::for_wrapper(&vast, wrapper), pod);
+    let pod = AdPod::for_wrapper(&vast, &crate::Wrapper::default());
+    assert!(pod.ads.is_empty());
+    assert_eq!(ids(&pod.buffet), ["a"]);
+    assert_eq!(pod.duration(), crate::Duration::default());
+    assert_eq!(ids(&pod.playback().collect::<Vec<_>>()), ["a"]);
+}
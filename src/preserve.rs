@@ -0,0 +1,615 @@
+//! A lossless representation of a document, for editing a response without dropping what the
+//! model does not know about.
+//!
+//! Every element, including the unknown ones, is kept with its attributes, comments and the order
+//! of its children, and is written back as it was read apart from the edits and the whitespace
+//! between attributes, which is written as a single space. An element can be read as, or replaced
+//! by, any type of the model, e.g. a [`Linear`](crate::Linear), keeping what the model does not
+//! know about.
+//!
+//! ```
+//! use vast4::preserve::Document;
+//!
+//! let xml = r#"<VAST version="4.2"><!-- served by acme --><Ad acme:id="1"><Wrapper>
+//!   <VASTAdTagURI><![CDATA[https://example.com/vast]]></VASTAdTagURI>
+//! </Wrapper></Ad></VAST>"#;
+//!
+//! let mut document = Document::parse(xml).unwrap();
+//! let uri = document.root_mut().find_mut("Ad[0]/Wrapper/VASTAdTagURI").unwrap();
+//! uri.set_text("https://example.com/vast?proxied=1");
+//!
+//! assert_eq!(
+//!     document.to_string(),
+//!     xml.replace("https://example.com/vast", "https://example.com/vast?proxied=1")
+//! );
+//! ```
+
+/// A document: the root element and the comments, declarations and whitespace around it.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Document {
+    /// The nodes of the document, in order. Exactly one of them is an element.
+    pub nodes: Vec<Node>,
+}
+
+/// A node of a [`Document`].
+#[derive(PartialEq, Clone, Debug)]
+pub enum Node {
+    Element(Element),
+    /// Character data, as written in the document, i.e. with entities escaped.
+    Text(String),
+    /// The content of a `<![CDATA[...]]>` section.
+    Cdata(String),
+    /// The content of a `<!--...-->` comment.
+    Comment(String),
+    /// A declaration, processing instruction or DTD, as written in the document.
+    Other(String),
+}
+
+/// An element, with every attribute and child it was read with.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct Element {
+    /// The qualified name, e.g. `InLine` or `vmap:AdBreak`.
+    pub name: String,
+    pub attributes: Vec<Attribute>,
+    pub children: Vec<Node>,
+    /// Whether the element is written as `<Name/>` rather than `<Name></Name>` when it has no
+    /// children.
+    pub self_closing: bool,
+}
+
+/// An attribute of an [`Element`].
+#[derive(PartialEq, Clone, Debug)]
+pub struct Attribute {
+    /// The qualified name, e.g. `type` or `xmlns:xsi`.
+    pub name: String,
+    /// The value, as written in the document, i.e. with entities escaped.
+    pub value: String,
+    /// The quote the value is enclosed in, `"` or `'`.
+    pub quote: char,
+}
+
+impl Document {
+    /// Reads a document, keeping every node.
+    pub fn parse(s: &str) -> crate::Result<Self> {
+        Self::read(s).map_err(|e| crate::Error::locate(s, e, |s| Self::read(s).map(drop)))
+    }
+
+    fn read(s: &str) -> hard_xml::XmlResult<Self> {
+        use hard_xml::xmlparser::{ElementEnd, Token, Tokenizer};
+
+        let mut nodes = Vec::new();
+        // the elements being read, innermost last
+        let mut stack: Vec<Element> = Vec::new();
+        // the end of the previous token, to keep the whitespace around the root element
+        let mut end = 0;
+
+        for token in Tokenizer::from(s) {
+            let token = token?;
+            let span = crate::error::token_span(&token);
+            if stack.is_empty() && span.start() > end {
+                nodes.push(Node::Text(s[end..span.start()].to_owned()));
+            }
+            end = span.end();
+
+            let node = match token {
+                Token::ElementStart { span, .. } => {
+                    let name = span.as_str()[1..].to_owned();
+                    stack.push(Element { name, ..Default::default() });
+                    continue;
+                }
+                Token::Attribute { prefix, local, value, span } => {
+                    let span = span.as_str();
+                    let name = match prefix.is_empty() {
+                        true => local.to_string(),
+                        false => span[..prefix.len() + 1 + local.len()].to_owned(),
+                    };
+                    let quote = span.chars().last().unwrap_or('"');
+                    let element = stack.last_mut().ok_or(hard_xml::XmlError::UnexpectedEof)?;
+                    element.attributes.push(Attribute { name, value: value.to_string(), quote });
+                    continue;
+                }
+                Token::ElementEnd { end: ElementEnd::Open, .. } => continue,
+                Token::ElementEnd { end, .. } => match stack.pop() {
+                    Some(mut element) => {
+                        element.self_closing = matches!(end, ElementEnd::Empty);
+                        Node::Element(element)
+                    }
+                    None => return Err(hard_xml::XmlError::UnexpectedEof),
+                },
+                Token::Text { text } => Node::Text(text.to_string()),
+                Token::Cdata { text, .. } => Node::Cdata(text.to_string()),
+                Token::Comment { text, .. } => Node::Comment(text.to_string()),
+                Token::Declaration { span, .. }
+                | Token::ProcessingInstruction { span, .. }
+                | Token::DtdStart { span, .. }
+                | Token::EmptyDtd { span, .. }
+                | Token::EntityDeclaration { span, .. }
+                | Token::DtdEnd { span } => Node::Other(span.to_string()),
+            };
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(node),
+                None => nodes.push(node),
+            }
+        }
+
+        if !stack.is_empty() {
+            return Err(hard_xml::XmlError::UnexpectedEof);
+        }
+        if end < s.len() {
+            nodes.push(Node::Text(s[end..].to_owned()));
+        }
+        match nodes.iter().filter(|n| matches!(n, Node::Element(_))).count() {
+            1 => Ok(Self { nodes }),
+            _ => Err(hard_xml::XmlError::UnexpectedToken { token: "root element".into() }),
+        }
+    }
+
+    /// Returns the root element, e.g. `<VAST>`.
+    pub fn root(&self) -> &Element {
+        self.nodes
+            .iter()
+            .find_map(|n| match n {
+                Node::Element(e) => Some(e),
+                _ => None,
+            })
+            .expect("a document has a root element")
+    }
+
+    /// Returns the root element, e.g. `<VAST>`.
+    pub fn root_mut(&mut self) -> &mut Element {
+        self.nodes
+            .iter_mut()
+            .find_map(|n| match n {
+                Node::Element(e) => Some(e),
+                _ => None,
+            })
+            .expect("a document has a root element")
+    }
+}
+
+impl std::fmt::Display for Document {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.nodes.iter().try_for_each(|n| write!(f, "{n}"))
+    }
+}
+
+impl std::fmt::Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Element(e) => write!(f, "{e}"),
+            Self::Text(text) | Self::Other(text) => write!(f, "{text}"),
+            Self::Cdata(text) => write!(f, "<![CDATA[{text}]]>"),
+            Self::Comment(text) => write!(f, "<!--{text}-->"),
+        }
+    }
+}
+
+impl std::fmt::Display for Element {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<{}", self.name)?;
+        for Attribute { name, value, quote } in &self.attributes {
+            write!(f, " {name}={quote}{value}{quote}")?;
+        }
+        if self.children.is_empty() && self.self_closing {
+            return write!(f, "/>");
+        }
+        write!(f, ">")?;
+        self.children.iter().try_for_each(|n| write!(f, "{n}"))?;
+        write!(f, "</{}>", self.name)
+    }
+}
+
+impl Element {
+    /// Converts a value of the model, e.g. a [`Linear`](crate::Linear), into an element.
+    pub fn from_struct<T: hard_xml::XmlWrite>(value: &T) -> hard_xml::XmlResult<Self> {
+        let document = Document::read(&hard_xml::XmlWrite::to_string(value)?)?;
+        Ok(document.root().clone())
+    }
+
+    /// Reads the element as a value of the model, e.g. a [`Linear`](crate::Linear). The element
+    /// is written into `buf`, which the returned value borrows from.
+    ///
+    /// The attributes and descendants the model does not know about are skipped, see
+    /// [`replace_with`](Element::replace_with) to write the value back with them.
+    pub fn as_struct<'a, T>(&self, buf: &'a mut String) -> crate::Result<T>
+    where
+        T: hard_xml::XmlRead<'a> + crate::IntoOwned,
+        T::Owned: crate::ReadOwned,
+    {
+        *buf = self.known::<T::Owned>();
+        crate::from_str(buf)
+    }
+
+    /// Replaces the element by `value`, e.g. an edited [`Linear`](crate::Linear) read with
+    /// [`as_struct`](Element::as_struct).
+    ///
+    /// Only what differs from the element read as the type of `value` is changed: the attributes
+    /// and descendants the model does not know about, the comments and the formatting of the
+    /// unchanged parts are kept. An element which can not be read as the type of `value` is
+    /// replaced as a whole.
+    pub fn replace_with<T>(&mut self, value: &T) -> hard_xml::XmlResult<()>
+    where
+        T: hard_xml::XmlWrite + crate::IntoOwned,
+        T::Owned: crate::ReadOwned + hard_xml::XmlWrite,
+    {
+        let new = Self::from_struct(value)?;
+        // the element as the model writes it, to find what the edit changed
+        let known = self.known::<T::Owned>();
+        let canon =
+            <<T::Owned as crate::ReadOwned>::Borrowed<'_> as hard_xml::XmlRead>::from_str(&known)
+                .ok()
+                .and_then(|old| Self::from_struct(&crate::IntoOwned::into_owned(old)).ok());
+        *self = match canon {
+            Some(canon) if canon.name == new.name => merge(self, &canon, new),
+            _ => new,
+        };
+        Ok(())
+    }
+
+    // Writes the element without the attributes and descendants the model does not know about.
+    // The element is read as each element of the models with its name, e.g. the `<Linear>` of
+    // VAST 4.x and of VAST 3.0, and the one `T` reads keeping the most is returned.
+    fn known<T: crate::ReadOwned>(&self) -> String {
+        let mut known: Option<String> = None;
+        let mut first = None;
+        for schema in crate::schema::find(&self.name) {
+            let mut element = self.clone();
+            element.strip(schema);
+            let s = element.to_string();
+            if known.as_ref().is_none_or(|known| s.len() > known.len())
+                && <T::Borrowed<'_> as hard_xml::XmlRead>::from_str(&s).is_ok()
+            {
+                known = Some(s);
+            } else if first.is_none() {
+                first = Some(s);
+            }
+        }
+        // the error reading it is the one of the model
+        known.or(first).unwrap_or_else(|| self.to_string())
+    }
+
+    // Removes the attributes and descendants `schema` does not have.
+    fn strip(&mut self, schema: &crate::schema::Element) {
+        if schema.raw {
+            return;
+        }
+        if let crate::schema::Attributes::Only(attributes) = schema.attributes {
+            self.attributes.retain(|a| attributes.iter().any(|&(name, _)| name == a.name));
+        }
+        self.children.retain_mut(|node| match node {
+            Node::Element(e) => match schema.children.iter().find(|&&(name, _)| name == e.name) {
+                Some(&(_, schema)) => {
+                    e.strip(schema);
+                    true
+                }
+                None => false,
+            },
+            _ => true,
+        });
+    }
+
+    /// Returns the unescaped value of the attribute.
+    pub fn attribute(&self, name: &str) -> Option<std::borrow::Cow<'_, str>> {
+        let attribute = self.attributes.iter().find(|a| a.name == name)?;
+        Some(
+            hard_xml::utils::xml_unescape(&attribute.value)
+                .unwrap_or(std::borrow::Cow::Borrowed(&attribute.value)),
+        )
+    }
+
+    /// Sets the value of the attribute, keeping its position if it exists already.
+    pub fn set_attribute(&mut self, name: &str, value: &str) {
+        let value = hard_xml::utils::xml_escape(value).into_owned();
+        match self.attributes.iter_mut().find(|a| a.name == name) {
+            Some(attribute) => {
+                attribute.value = value;
+                attribute.quote = '"';
+            }
+            None => self.attributes.push(Attribute { name: name.to_owned(), value, quote: '"' }),
+        }
+    }
+
+    /// Removes the attribute, returning whether it existed.
+    pub fn remove_attribute(&mut self, name: &str) -> bool {
+        let len = self.attributes.len();
+        self.attributes.retain(|a| a.name != name);
+        self.attributes.len() != len
+    }
+
+    /// Returns the unescaped text and CDATA content of the element, without its children.
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        for node in &self.children {
+            match node {
+                Node::Text(t) => {
+                    text.push_str(&hard_xml::utils::xml_unescape(t).unwrap_or_else(|_| t.into()))
+                }
+                Node::Cdata(t) => text.push_str(t),
+                _ => {}
+            }
+        }
+        text
+    }
+
+    /// Replaces the text content of the element. The text is written as CDATA if the element
+    /// held CDATA, e.g. a URI, and escaped otherwise.
+    pub fn set_text(&mut self, text: &str) {
+        let cdata =
+            self.children.iter().any(|n| matches!(n, Node::Cdata(_))) && !text.contains("]]>");
+        let node = match cdata {
+            true => Node::Cdata(text.to_owned()),
+            false => Node::Text(hard_xml::utils::xml_escape(text).into_owned()),
+        };
+
+        let mut node = Some(node);
+        let mut children = Vec::with_capacity(self.children.len() + 1);
+        for child in self.children.drain(..) {
+            match child {
+                // the new text takes the place of the first text node
+                Node::Cdata(_) => children.extend(node.take()),
+                Node::Text(ref t) if !t.trim().is_empty() => children.extend(node.take()),
+                child => children.push(child),
+            }
+        }
+        if let Some(node) = node {
+            // insert it after the leading whitespace of an element without text
+            let i = children.iter().take_while(|n| matches!(n, Node::Text(_))).count();
+            children.insert(i, node);
+        }
+        self.children = children;
+    }
+
+    /// Returns the child elements, in order.
+    pub fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|n| match n {
+            Node::Element(e) => Some(e),
+            _ => None,
+        })
+    }
+
+    /// Returns the child elements, in order.
+    pub fn elements_mut(&mut self) -> impl Iterator<Item = &mut Element> {
+        self.children.iter_mut().filter_map(|n| match n {
+            Node::Element(e) => Some(e),
+            _ => None,
+        })
+    }
+
+    /// Returns the first child element named `name`.
+    pub fn child(&self, name: &str) -> Option<&Element> {
+        self.elements().find(|e| e.name == name)
+    }
+
+    /// Returns the first child element named `name`.
+    pub fn child_mut(&mut self, name: &str) -> Option<&mut Element> {
+        self.elements_mut().find(|e| e.name == name)
+    }
+
+    /// Finds a descendant by a path relative to the element, in the format of
+    /// [`Diagnostic::path`](crate::Diagnostic::path), e.g. `Ad[0]/InLine/Creatives/Creative[1]`.
+    /// A segment without an index is the first child of that name.
+    pub fn find(&self, path: &str) -> Option<&Element> {
+        path.split('/').filter(|s| !s.is_empty()).try_fold(self, |element, segment| {
+            let (name, i) = parse_segment(segment)?;
+            element.elements().filter(|e| e.name == name).nth(i)
+        })
+    }
+
+    /// Finds a descendant by a path relative to the element, see [`find`](Element::find).
+    pub fn find_mut(&mut self, path: &str) -> Option<&mut Element> {
+        path.split('/').filter(|s| !s.is_empty()).try_fold(self, |element, segment| {
+            let (name, i) = parse_segment(segment)?;
+            element.elements_mut().filter(|e| e.name == name).nth(i)
+        })
+    }
+}
+
+// Applies the edit from `canon`, `old` as the model writes it, to `new` onto `old`. The
+// attributes and children `canon` does not have are unknown to the model and kept, and so are
+// the unchanged children as they were read.
+fn merge(old: &Element, canon: &Element, new: Element) -> Element {
+    if *canon == new {
+        return old.clone();
+    }
+
+    let mut attributes = Vec::new();
+    for attribute in &old.attributes {
+        let Some(known) = canon.attributes.iter().find(|a| a.name == attribute.name) else {
+            attributes.push(attribute.clone());
+            continue;
+        };
+        match new.attributes.iter().find(|a| a.name == attribute.name) {
+            Some(a) if a.value == known.value => attributes.push(attribute.clone()),
+            Some(a) => attributes.push(Attribute { quote: '"', ..a.clone() }),
+            None => {}
+        }
+    }
+    for attribute in &new.attributes {
+        if !old.attributes.iter().any(|a| a.name == attribute.name) {
+            attributes.push(attribute.clone());
+        }
+    }
+
+    let text = (canon.text() != new.text()).then(|| new.text());
+    let mut pending: Vec<Option<Element>> = (new.children.into_iter())
+        .filter_map(|n| match n {
+            Node::Element(e) => Some(Some(e)),
+            _ => None,
+        })
+        .collect();
+
+    // the children, with the index in `pending` of those from `new`
+    let mut children: Vec<(Node, Option<usize>)> = Vec::new();
+    let mut counts = std::collections::HashMap::new();
+    for node in &old.children {
+        let element = match node {
+            Node::Element(e) if canon.child(&e.name).is_some() => e,
+            node => {
+                children.push((node.clone(), None));
+                continue;
+            }
+        };
+        // the n-th child of a name is paired with the n-th one of `canon` and of `new`
+        let count = counts.entry(element.name.as_str()).or_insert(0);
+        let canon = canon.elements().filter(|e| e.name == element.name).nth(*count);
+        *count += 1;
+        let i = pending.iter().position(|e| e.as_ref().is_some_and(|e| e.name == element.name));
+        match (canon, i) {
+            (Some(canon), Some(i)) => {
+                let new = pending[i].take().expect("a pending element");
+                children.push((Node::Element(merge(element, canon, new)), Some(i)));
+            }
+            (None, Some(i)) => {
+                let new = pending[i].take().expect("a pending element");
+                children.push((Node::Element(new), Some(i)));
+            }
+            // removed by the edit, with the whitespace before it
+            (_, None) => {
+                if matches!(children.last(), Some((Node::Text(t), _)) if t.trim().is_empty()) {
+                    children.pop();
+                }
+            }
+        }
+    }
+
+    // the children added by the edit go after their preceding sibling in `new`
+    for (i, new) in pending.into_iter().enumerate() {
+        let Some(new) = new else { continue };
+        let before = children.iter().rposition(|(_, j)| j.is_some_and(|j| j < i));
+        let position = before.or_else(|| children.iter().position(|(_, j)| j.is_some()));
+        let Some(position) = position else {
+            children.push((Node::Element(new), Some(i)));
+            continue;
+        };
+        let indent = match position.checked_sub(1).map(|p| &children[p].0) {
+            Some(Node::Text(t)) if t.trim().is_empty() => Some(Node::Text(t.clone())),
+            _ => None,
+        };
+        let (new, indent) = ((Node::Element(new), Some(i)), indent.map(|n| (n, None)));
+        let (at, nodes): (_, Vec<_>) = match before {
+            // after the preceding sibling, indented like it
+            Some(_) => (position + 1, indent.into_iter().chain([new]).collect()),
+            // before the first sibling, indented like it
+            None => (position, std::iter::once(new).chain(indent).collect()),
+        };
+        children.splice(at..at, nodes);
+    }
+
+    let mut element = Element {
+        name: old.name.clone(),
+        attributes,
+        children: children.into_iter().map(|(n, _)| n).collect(),
+        self_closing: old.self_closing,
+    };
+    if let Some(text) = text {
+        element.set_text(&text);
+    }
+    element
+}
+
+// Splits `Name[i]` into the name and the index.
+fn parse_segment(segment: &str) -> Option<(&str, usize)> {
+    match segment.strip_suffix(']').and_then(|s| s.split_once('[')) {
+        Some((name, i)) => Some((name, i.parse().ok()?)),
+        None => Some((segment, 0)),
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_preserve() {
+    const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- served by acme -->
+<VAST version="4.2" xmlns:acme='https://acme.example.com'>
+  <Ad id="1" acme:campaign="42">
+    <InLine>
+      <AdSystem version="1">acme</AdSystem>
+      <AdTitle>Fish &amp; Chips</AdTitle>
+      <acme:Targeting><acme:Segment id="7"/></acme:Targeting>
+      <Creatives>
+        <Creative id="c1">
+          <Linear>
+            <Duration>00:00:15</Duration>
+            <!-- progressive only -->
+            <MediaFiles>
+              <MediaFile delivery="progressive" type="video/mp4" width="640" height="360" acme:cdn="a">
+                <![CDATA[https://cdn-a.example.com/ad.mp4]]>
+              </MediaFile>
+            </MediaFiles>
+          </Linear>
+        </Creative>
+      </Creatives>
+    </InLine>
+  </Ad>
+</VAST>
+"#;
+
+    let mut document = Document::parse(XML).unwrap();
+    assert_eq!(document.to_string(), XML);
+
+    let in_line = document.root().find("Ad/InLine").unwrap();
+    assert_eq!(in_line.child("AdTitle").unwrap().text(), "Fish & Chips");
+    assert_eq!(in_line.find("acme:Targeting/acme:Segment").unwrap().attribute("id").unwrap(), "7");
+    assert!(document.root().find("Ad[1]").is_none());
+
+    let media_file = document
+        .root_mut()
+        .find_mut("Ad[0]/InLine/Creatives/Creative[0]/Linear/MediaFiles/MediaFile[0]")
+        .unwrap();
+    assert_eq!(media_file.text().trim(), "https://cdn-a.example.com/ad.mp4");
+    media_file.set_text("https://cdn-b.example.com/ad.mp4?a=1&b=2");
+    media_file.set_attribute("acme:cdn", "b");
+    assert_eq!(
+        document.to_string(),
+        XML.replace(
+            "<![CDATA[https://cdn-a.example.com/ad.mp4]]>",
+            "<![CDATA[https://cdn-b.example.com/ad.mp4?a=1&b=2]]>"
+        )
+        .replace(r#"acme:cdn="a""#, r#"acme:cdn="b""#)
+    );
+
+    let before = document.to_string();
+    let linear = document.root_mut().find_mut("Ad/InLine/Creatives/Creative/Linear").unwrap();
+    let mut buf = String::new();
+    // the unknown attribute of <MediaFile> is skipped
+    let mut value = linear.as_struct::<crate::Linear>(&mut buf).unwrap();
+    assert_eq!(value.media_files.as_ref().unwrap().media_files[0].width, 640);
+    value.duration = Some(crate::Duration::new(0, 0, 20, 0));
+    value.skipoffset = Some(crate::Duration::new(0, 0, 5, 0));
+    linear.replace_with(&value).unwrap();
+    assert_eq!(
+        document.to_string(),
+        before
+            .replace("<Linear>", r#"<Linear skipoffset="00:00:05">"#)
+            .replace("<Duration>00:00:15</Duration>", "<Duration>00:00:20</Duration>")
+    );
+
+    let linear = document.root_mut().find_mut("Ad/InLine/Creatives/Creative/Linear").unwrap();
+    let mut value = linear.as_struct::<crate::Linear>(&mut buf).unwrap();
+    value.skipoffset = None;
+    value.tracking_events = Some(crate::TrackingEvents {
+        trackings: vec![crate::Tracking {
+            event: crate::TrackingEvent::Start,
+            offset: None,
+            uri: "https://example.com/start".into(),
+        }],
+    });
+    linear.replace_with(&value).unwrap();
+    assert_eq!(
+        document.to_string(),
+        before.replace(
+            "<Duration>00:00:15</Duration>",
+            "<TrackingEvents><Tracking event=\"start\"><![CDATA[https://example.com/start]]>\
+             </Tracking></TrackingEvents>\n            <Duration>00:00:20</Duration>"
+        )
+    );
+
+    let linear = document.root_mut().find_mut("Ad/InLine/Creatives/Creative/Linear").unwrap();
+    let duration = linear.child_mut("Duration").unwrap();
+    assert_eq!(
+        duration.as_struct::<crate::Duration>(&mut buf).unwrap(),
+        crate::Duration::new(0, 0, 20, 0)
+    );
+    duration.replace_with(&crate::Duration::new(0, 0, 30, 0)).unwrap();
+    assert_eq!(duration.to_string(), "<Duration>00:00:30</Duration>");
+}
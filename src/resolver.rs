@@ -0,0 +1,300 @@
+/// Fetches the VAST response referenced by a [`Wrapper::vast_ad_tag_uri`](crate::Wrapper).
+///
+/// The fetched document is returned already parsed, so the implementation decides who owns the
+/// underlying XML text (an in-memory fixture table, a cache, an arena, ...).
+pub trait Fetcher<'a> {
+    /// The error returned when the VAST response can not be fetched or parsed.
+    type Error;
+
+    /// Fetches and parses the VAST response located at `uri`.
+    fn fetch(&mut self, uri: &str) -> Result<crate::Vast<'a>, Self::Error>;
+}
+
+/// The asynchronous variant of [`Fetcher`].
+#[cfg(feature = "async")]
+pub trait AsyncFetcher<'a> {
+    /// The error returned when the VAST response can not be fetched or parsed.
+    type Error;
+
+    /// Fetches and parses the VAST response located at `uri`.
+    fn fetch(
+        &mut self,
+        uri: &str,
+    ) -> impl std::future::Future<Output = Result<crate::Vast<'a>, Self::Error>>;
+}
+
+/// Follows [`Wrapper`](crate::Wrapper) ads down to the final [`InLine`](crate::InLine) ads.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Resolver {
+    /// The maximum number of wrappers followed for a single ad before giving up with a wrapper
+    /// limit error. Default value is 5.
+    pub max_depth: usize,
+}
+
+impl Default for Resolver {
+    fn default() -> Self {
+        Self { max_depth: 5 }
+    }
+}
+
+/// An [`InLine`](crate::InLine) ad reached by following zero or more wrappers.
+#[derive(PartialEq, Clone, Debug)]
+pub struct ResolvedAd<'a> {
+    /// The [`Ad`](crate::Ad)s holding the wrappers that were followed, outermost first.
+    pub wrappers: Vec<crate::Ad<'a>>,
+    /// The [`Ad`](crate::Ad) holding the final [`InLine`](crate::InLine).
+    pub ad: crate::Ad<'a>,
+}
+
+/// Represents an error for resolving a wrapper chain.
+#[derive(Debug)]
+pub enum ResolveError<E> {
+    /// The [`Ad`](crate::Ad) holds neither an [`InLine`](crate::InLine) nor a
+    /// [`Wrapper`](crate::Wrapper).
+    EmptyAd,
+    /// More than [`Resolver::max_depth`] wrappers were received without an inline ad.
+    WrapperLimit { depth: usize },
+    /// The fetcher failed to fetch the VAST response.
+    Fetch { uri: String, error: E },
+    /// The VAST response of a wrapper contained no usable ads.
+    NoAds {
+        uri: String,
+        /// The [`Wrapper::fallback_on_no_ad`](crate::Wrapper) value of the wrapper.
+        fallback_on_no_ad: bool,
+    },
+}
+
+impl<E> ResolveError<E> {
+    /// Returns the error code the media player should report for this error.
+    pub fn code(&self) -> crate::VastErrorCode {
+        match self {
+            Self::EmptyAd => crate::VastErrorCode::SchemaValidation,
+            Self::WrapperLimit { .. } => crate::VastErrorCode::WrapperLimit,
+            Self::Fetch { .. } => crate::VastErrorCode::WrapperTimeout,
+            Self::NoAds { .. } => crate::VastErrorCode::NoAdsAfterWrapper,
+        }
+    }
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for ResolveError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyAd => write!(f, "ad has neither an inline nor a wrapper"),
+            Self::WrapperLimit { depth } => write!(f, "wrapper limit reached: {depth}"),
+            Self::Fetch { uri, error } => write!(f, "failed to fetch '{uri}': {error}"),
+            Self::NoAds { uri, .. } => write!(f, "no ads in the VAST response of '{uri}'"),
+        }
+    }
+}
+
+impl<E: std::error::Error> std::error::Error for ResolveError<E> {}
+
+struct Pending<'a> {
+    wrappers: Vec<crate::Ad<'a>>,
+    ad: crate::Ad<'a>,
+}
+
+impl Resolver {
+    /// Resolves every ad of `vast`. Each wrapper is replaced by the ads found at the end of its
+    /// chain, so the returned list keeps the document order and may hold more entries than
+    /// `vast.ads` when a wrapper allows multiple ads.
+    pub fn resolve<'a, F: Fetcher<'a>>(
+        &self,
+        vast: crate::Vast<'a>,
+        fetcher: &mut F,
+    ) -> Vec<Result<ResolvedAd<'a>, ResolveError<F::Error>>> {
+        let mut stack = Self::initial(vast);
+        let mut resolved = Vec::new();
+
+        while let Some(pending) = stack.pop() {
+            let uri = match self.next_uri(&pending) {
+                Ok(Some(uri)) => uri,
+                Ok(None) => {
+                    resolved.push(Ok(ResolvedAd { wrappers: pending.wrappers, ad: pending.ad }));
+                    continue;
+                }
+                Err(e) => {
+                    resolved.push(Err(e));
+                    continue;
+                }
+            };
+            match fetcher.fetch(&uri) {
+                Ok(vast) => Self::expand(pending, uri, vast, &mut stack, &mut resolved),
+                Err(error) => resolved.push(Err(ResolveError::Fetch { uri, error })),
+            }
+        }
+
+        resolved
+    }
+
+    /// The asynchronous variant of [`Resolver::resolve`].
+    #[cfg(feature = "async")]
+    pub async fn resolve_async<'a, F: AsyncFetcher<'a>>(
+        &self,
+        vast: crate::Vast<'a>,
+        fetcher: &mut F,
+    ) -> Vec<Result<ResolvedAd<'a>, ResolveError<F::Error>>> {
+        let mut stack = Self::initial(vast);
+        let mut resolved = Vec::new();
+
+        while let Some(pending) = stack.pop() {
+            let uri = match self.next_uri(&pending) {
+                Ok(Some(uri)) => uri,
+                Ok(None) => {
+                    resolved.push(Ok(ResolvedAd { wrappers: pending.wrappers, ad: pending.ad }));
+                    continue;
+                }
+                Err(e) => {
+                    resolved.push(Err(e));
+                    continue;
+                }
+            };
+            match fetcher.fetch(&uri).await {
+                Ok(vast) => Self::expand(pending, uri, vast, &mut stack, &mut resolved),
+                Err(error) => resolved.push(Err(ResolveError::Fetch { uri, error })),
+            }
+        }
+
+        resolved
+    }
+
+    fn initial(vast: crate::Vast<'_>) -> Vec<Pending<'_>> {
+        vast.ads.into_iter().rev().map(|ad| Pending { wrappers: Vec::new(), ad }).collect()
+    }
+
+    // Returns the URI to fetch next, or `None` when the pending ad is an inline ad.
+    fn next_uri<E>(&self, pending: &Pending<'_>) -> Result<Option<String>, ResolveError<E>> {
+        if pending.ad.in_line.is_some() {
+            return Ok(None);
+        }
+        let Some(ref wrapper) = pending.ad.wrapper else {
+            return Err(ResolveError::EmptyAd);
+        };
+        if pending.wrappers.len() >= self.max_depth {
+            return Err(ResolveError::WrapperLimit { depth: pending.wrappers.len() + 1 });
+        }
+        Ok(Some(wrapper.vast_ad_tag_uri.trim().to_owned()))
+    }
+
+    fn expand<'a, E>(
+        pending: Pending<'a>,
+        uri: String,
+        vast: crate::Vast<'a>,
+        stack: &mut Vec<Pending<'a>>,
+        resolved: &mut Vec<Result<ResolvedAd<'a>, ResolveError<E>>>,
+    ) {
+        let wrapper = pending.ad.wrapper.as_ref().expect("pending ad is a wrapper");
+        let follow_additional_wrappers = wrapper.follow_additional_wrappers.unwrap_or(true);
+        let allow_multiple_ads = wrapper.allow_multiple_ads.unwrap_or(false);
+        let fallback_on_no_ad = wrapper.fallback_on_no_ad.unwrap_or(false);
+
+        let mut ads = vast
+            .ads
+            .into_iter()
+            .filter(|ad| follow_additional_wrappers || ad.wrapper.is_none())
+            .collect::<Vec<_>>();
+        if !allow_multiple_ads {
+            ads = ads.into_iter().filter(|ad| ad.sequence.is_none()).take(1).collect();
+        }
+
+        if ads.is_empty() {
+            resolved.push(Err(ResolveError::NoAds { uri, fallback_on_no_ad }));
+            return;
+        }
+
+        let mut wrappers = pending.wrappers;
+        wrappers.push(pending.ad);
+        for ad in ads.into_iter().rev() {
+            stack.push(Pending { wrappers: wrappers.clone(), ad });
+        }
+    }
+}
+
+#[cfg(test)]
+struct MemoryFetcher(std::collections::HashMap<&'static str, &'static str>);
+
+#[cfg(test)]
+impl<'a> Fetcher<'a> for MemoryFetcher {
+    type Error = String;
+
+    fn fetch(&mut self, uri: &str) -> Result<crate::Vast<'a>, Self::Error> {
+        let xml = self.0.get(uri).ok_or_else(|| format!("not found: {uri}"))?;
+        crate::from_str(xml).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+const WRAPPER_TAG_URI: &str = "https://raw.githubusercontent.com/InteractiveAdvertisingBureau/VAST_Samples/master/VAST%204.2%20Samples/Inline_Companion_Tag-test.xml";
+
+#[cfg(test)]
+#[test]
+fn test_resolve_wrapper() {
+    let mut fetcher = MemoryFetcher(
+        [(WRAPPER_TAG_URI, include_str!("../tests/data/v4_2/Inline_Companion_Tag-test.xml"))]
+            .into(),
+    );
+    let vast = crate::from_str(include_str!("../tests/data/v4_2/Wrapper_Tag-test.xml")).unwrap();
+
+    let resolved = Resolver::default().resolve(vast, &mut fetcher);
+    assert_eq!(resolved.len(), 1);
+    let resolved = resolved.into_iter().next().unwrap().unwrap();
+    assert_eq!(resolved.wrappers.len(), 1);
+    assert_eq!(resolved.wrappers[0].id.as_deref(), Some("20011"));
+    assert_eq!(resolved.ad.id.as_deref(), Some("20004"));
+    assert!(resolved.ad.in_line.is_some());
+}
+
+#[cfg(test)]
+#[test]
+fn test_resolve_wrapper_errors() {
+    const LOOP: &str = r#"<VAST version="4.2"><Ad><Wrapper allowMultipleAds="true"><AdSystem>a</AdSystem><Impression>i</Impression><VASTAdTagURI>loop</VASTAdTagURI></Wrapper></Ad></VAST>"#;
+    const POD: &str = r#"<VAST version="4.2"><Ad sequence="1"><Wrapper><AdSystem>a</AdSystem><Impression>i</Impression><VASTAdTagURI>loop</VASTAdTagURI></Wrapper></Ad></VAST>"#;
+    const SINGLE: &str = r#"<VAST version="4.2"><Ad><Wrapper><AdSystem>a</AdSystem><Impression>i</Impression><VASTAdTagURI>pod</VASTAdTagURI></Wrapper></Ad></VAST>"#;
+    const NO_FOLLOW: &str = r#"<VAST version="4.2"><Ad><Wrapper followAdditionalWrappers="false" fallbackOnNoAd="true"><AdSystem>a</AdSystem><Impression>i</Impression><VASTAdTagURI>loop</VASTAdTagURI></Wrapper></Ad></VAST>"#;
+
+    let mut fetcher = MemoryFetcher([("loop", LOOP), ("pod", POD)].into());
+
+    let resolved = Resolver { max_depth: 3 }.resolve(crate::from_str(LOOP).unwrap(), &mut fetcher);
+    assert!(matches!(resolved[..], [Err(ResolveError::WrapperLimit { depth: 4 })]));
+    assert_eq!(resolved[0].as_ref().unwrap_err().code(), crate::VastErrorCode::WrapperLimit);
+
+    // `allowMultipleAds` is false by default, so the pod ad is dropped.
+    let resolved = Resolver::default().resolve(crate::from_str(SINGLE).unwrap(), &mut fetcher);
+    assert!(matches!(resolved[..], [Err(ResolveError::NoAds { fallback_on_no_ad: false, .. })]));
+
+    let resolved = Resolver::default().resolve(crate::from_str(NO_FOLLOW).unwrap(), &mut fetcher);
+    assert!(matches!(resolved[..], [Err(ResolveError::NoAds { fallback_on_no_ad: true, .. })]));
+
+    let missing = LOOP.replace("loop", "missing");
+    let resolved = Resolver::default().resolve(crate::from_str(&missing).unwrap(), &mut fetcher);
+    assert!(matches!(resolved[..], [Err(ResolveError::Fetch { .. })]));
+}
+
+#[cfg(all(test, feature = "async"))]
+impl<'a> AsyncFetcher<'a> for MemoryFetcher {
+    type Error = String;
+
+    async fn fetch(&mut self, uri: &str) -> Result<crate::Vast<'a>, Self::Error> {
+        Fetcher::fetch(self, uri)
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+#[test]
+fn test_resolve_wrapper_async() {
+    let mut fetcher = MemoryFetcher(
+        [(WRAPPER_TAG_URI, include_str!("../tests/data/v4_2/Inline_Companion_Tag-test.xml"))]
+            .into(),
+    );
+    let vast = crate::from_str(include_str!("../tests/data/v4_2/Wrapper_Tag-test.xml")).unwrap();
+
+    let resolver = Resolver::default();
+    let mut future = std::pin::pin!(resolver.resolve_async(vast, &mut fetcher));
+    let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+    let std::task::Poll::Ready(resolved) = std::future::Future::poll(future.as_mut(), &mut cx)
+    else {
+        panic!("in-memory fetcher never pends");
+    };
+    assert_eq!(resolved.len(), 1);
+    assert_eq!(resolved[0].as_ref().unwrap().ad.id.as_deref(), Some("20004"));
+}
@@ -0,0 +1,702 @@
+//! The elements and attributes of the model, to read documents with parts the model does not
+//! know about, see [`from_str_lenient`](crate::from_str_lenient) and
+//! [`Element::as_struct`](crate::preserve::Element::as_struct).
+//!
+//! The tables are checked against the declarations of the model by `test_tables`.
+
+/// The value of an attribute.
+#[derive(Clone, Copy)]
+pub(crate) enum Value {
+    Text,
+    Bool,
+    Enum(&'static [&'static str]),
+}
+
+/// The attributes of an element.
+pub(crate) enum Attributes {
+    Any,
+    Only(&'static [(&'static str, Value)]),
+}
+
+/// An element of the model, with its children by name.
+pub(crate) struct Element {
+    pub(crate) attributes: Attributes,
+    pub(crate) children: &'static [(&'static str, &'static Element)],
+    /// Whether the content is kept as is, e.g. `<Extension>`.
+    pub(crate) raw: bool,
+}
+
+/// Returns the elements of the models named `name`, e.g. both `<Linear>` of VAST 4.x and 3.0.
+pub(crate) fn find(name: &str) -> Vec<&'static Element> {
+    fn visit(
+        name: &str,
+        children: &'static [(&'static str, &'static Element)],
+        found: &mut Vec<&'static Element>,
+        visited: &mut Vec<&'static Element>,
+    ) {
+        for &(n, element) in children {
+            if visited.iter().any(|e| std::ptr::eq(*e, element)) {
+                continue;
+            }
+            visited.push(element);
+            if n == name {
+                found.push(element);
+            }
+            visit(name, element.children, found, visited);
+        }
+    }
+
+    let (mut found, mut visited) = (Vec::new(), Vec::new());
+    for root in [ROOT, v3::ROOT, vmap::ROOT] {
+        visit(name, root, &mut found, &mut visited);
+    }
+    found
+}
+
+const fn element(
+    attributes: &'static [(&'static str, Value)],
+    children: &'static [(&'static str, &'static Element)],
+) -> Element {
+    Element { attributes: Attributes::Only(attributes), children, raw: false }
+}
+
+const T: Value = Value::Text;
+const B: Value = Value::Bool;
+
+/// The root of VAST 4.x.
+pub(crate) static ROOT: &[(&str, &Element)] = &[("VAST", &VAST)];
+// text only elements, e.g. `<AdTitle>`
+static TEXT: Element = Element { attributes: Attributes::Any, children: &[], raw: false };
+
+static VAST: Element =
+    Element { attributes: Attributes::Any, children: &[("Ad", &AD), ("Error", &TEXT)], raw: false };
+static AD: Element = element(
+    &[("id", T), ("sequence", T), ("conditionalAd", B), ("adType", T)],
+    &[("InLine", &IN_LINE), ("Wrapper", &WRAPPER)],
+);
+static IN_LINE: Element = element(
+    &[],
+    &[
+        ("AdSystem", &AD_SYSTEM),
+        ("Error", &TEXT),
+        ("Extensions", &EXTENSIONS),
+        ("Impression", &URI),
+        ("Pricing", &PRICING),
+        ("ViewableImpression", &VIEWABLE_IMPRESSION),
+        ("AdServingId", &TEXT),
+        ("AdTitle", &TEXT),
+        ("AdVerifications", &AD_VERIFICATIONS),
+        ("Advertiser", &TEXT),
+        ("Category", &CATEGORY),
+        ("Creatives", &CREATIVES),
+        ("Description", &TEXT),
+        ("Expires", &TEXT),
+        ("Survey", &SURVEY),
+    ],
+);
+static WRAPPER: Element = element(
+    &[("followAdditionalWrappers", B), ("allowMultipleAds", B), ("fallbackOnNoAd", B)],
+    &[
+        ("AdSystem", &AD_SYSTEM),
+        ("Error", &TEXT),
+        ("Extensions", &EXTENSIONS),
+        ("Impression", &URI),
+        ("Pricing", &PRICING),
+        ("ViewableImpression", &VIEWABLE_IMPRESSION),
+        ("AdVerifications", &AD_VERIFICATIONS),
+        ("BlockedAdCategories", &CATEGORY),
+        ("Creatives", &CREATIVES),
+        ("VASTAdTagURI", &TEXT),
+    ],
+);
+// an URI with an optional identifier, e.g. `<Impression>`
+static URI: Element = element(&[("id", T)], &[]);
+static AD_SYSTEM: Element = element(&[("version", T)], &[]);
+static CATEGORY: Element = element(&[("authority", T)], &[]);
+static PRICING: Element = element(
+    &[
+        ("model", Value::Enum(&["cpm", "cpc", "cpe", "cpv", "CPM", "CPC", "CPE", "CPV"])),
+        ("currency", T),
+    ],
+    &[],
+);
+static SURVEY: Element = element(&[("type", T)], &[]);
+static VIEWABLE_IMPRESSION: Element = element(
+    &[("id", T)],
+    &[("Viewable", &TEXT), ("NotViewable", &TEXT), ("ViewUndetermined", &TEXT)],
+);
+static AD_VERIFICATIONS: Element = element(&[], &[("Verification", &VERIFICATION)]);
+static VERIFICATION: Element = element(
+    &[("vendor", T)],
+    &[
+        ("ExecutableResource", &EXECUTABLE_RESOURCE),
+        ("JavaScriptResource", &JAVASCRIPT_RESOURCE),
+        ("TrackingEvents", &VERIFICATION_TRACKING_EVENTS),
+        ("VerificationParameters", &TEXT),
+    ],
+);
+static EXECUTABLE_RESOURCE: Element = element(&[("apiFramework", T), ("type", T)], &[]);
+static JAVASCRIPT_RESOURCE: Element = element(&[("apiFramework", T), ("browserOptional", B)], &[]);
+static VERIFICATION_TRACKING_EVENTS: Element =
+    element(&[], &[("Tracking", &VERIFICATION_TRACKING)]);
+static VERIFICATION_TRACKING: Element = element(&[("event", T)], &[]);
+static EXTENSIONS: Element = element(&[], &[("Extension", &EXTENSION)]);
+static EXTENSION: Element =
+    Element { attributes: Attributes::Only(&[("type", T)]), children: &[], raw: true };
+static CREATIVES: Element = element(&[], &[("Creative", &CREATIVE)]);
+static CREATIVE: Element = element(
+    &[("sequence", T), ("apiFramework", T), ("id", T), ("adId", T)],
+    &[
+        ("CompanionAds", &COMPANION_ADS),
+        ("CreativeExtensions", &CREATIVE_EXTENSIONS),
+        ("Linear", &LINEAR),
+        ("NonLinearAds", &NON_LINEAR_ADS),
+        ("UniversalAdId", &UNIVERSAL_AD_ID),
+    ],
+);
+static UNIVERSAL_AD_ID: Element = element(&[("idRegistry", T)], &[]);
+static CREATIVE_EXTENSIONS: Element = element(&[], &[("CreativeExtension", &EXTENSION)]);
+static LINEAR: Element = element(
+    &[("skipoffset", T)],
+    &[
+        ("Icons", &ICONS),
+        ("TrackingEvents", &TRACKING_EVENTS),
+        ("AdParameters", &AD_PARAMETERS),
+        ("Duration", &TEXT),
+        ("MediaFiles", &MEDIA_FILES),
+        ("VideoClicks", &VIDEO_CLICKS),
+    ],
+);
+static AD_PARAMETERS: Element = element(&[("xmlEncoded", B)], &[]);
+static MEDIA_FILES: Element = element(
+    &[],
+    &[
+        ("ClosedCaptionFiles", &CLOSED_CAPTION_FILES),
+        ("MediaFile", &MEDIA_FILE),
+        ("Mezzanine", &MEZZANINE),
+        ("InteractiveCreativeFile", &INTERACTIVE_CREATIVE_FILE),
+    ],
+);
+const DELIVERY: Value = Value::Enum(&["progressive", "streaming"]);
+static MEDIA_FILE: Element = element(
+    &[
+        ("id", T),
+        ("delivery", DELIVERY),
+        ("type", T),
+        ("width", T),
+        ("height", T),
+        ("codec", T),
+        ("bitrate", T),
+        ("minBitrate", T),
+        ("maxBitrate", T),
+        ("scalable", B),
+        ("maintainAspectRatio", B),
+        ("fileSize", T),
+        ("mediaType", T),
+        ("apiFramework", T),
+    ],
+    &[],
+);
+static MEZZANINE: Element = element(
+    &[
+        ("id", T),
+        ("delivery", DELIVERY),
+        ("type", T),
+        ("width", T),
+        ("height", T),
+        ("codec", T),
+        ("fileSize", T),
+        ("mediaType", T),
+    ],
+    &[],
+);
+static INTERACTIVE_CREATIVE_FILE: Element =
+    element(&[("type", T), ("apiFramework", T), ("variableDuration", B)], &[]);
+static CLOSED_CAPTION_FILES: Element = element(&[], &[("ClosedCaptionFile", &CLOSED_CAPTION_FILE)]);
+static CLOSED_CAPTION_FILE: Element = element(&[("type", T), ("language", T)], &[]);
+static VIDEO_CLICKS: Element =
+    element(&[], &[("ClickTracking", &URI), ("ClickThrough", &URI), ("CustomClick", &URI)]);
+static ICONS: Element = element(&[], &[("Icon", &ICON)]);
+static ICON: Element = element(
+    &[
+        ("program", T),
+        ("width", T),
+        ("height", T),
+        ("xPosition", Value::Enum(&["left", "right"])),
+        ("yPosition", Value::Enum(&["top", "bottom"])),
+        ("duration", T),
+        ("offset", T),
+        ("apiFramework", T),
+        ("pxratio", T),
+    ],
+    &[
+        ("HTMLResource", &TEXT),
+        ("IFrameResource", &TEXT),
+        ("StaticResource", &STATIC_RESOURCE),
+        ("IconClicks", &ICON_CLICKS),
+        ("IconViewTracking", &TEXT),
+    ],
+);
+static ICON_CLICKS: Element = element(
+    &[],
+    &[
+        ("IconClickFallbackImages", &ICON_CLICK_FALLBACK_IMAGES),
+        ("IconClickThrough", &TEXT),
+        ("IconClickTracking", &URI),
+    ],
+);
+static ICON_CLICK_FALLBACK_IMAGES: Element =
+    element(&[], &[("IconClickFallbackImage", &ICON_CLICK_FALLBACK_IMAGE)]);
+static ICON_CLICK_FALLBACK_IMAGE: Element =
+    element(&[("height", T), ("width", T)], &[("AltText", &TEXT), ("StaticResource", &TEXT)]);
+static STATIC_RESOURCE: Element = element(&[("creativeType", T)], &[]);
+static NON_LINEAR_ADS: Element =
+    element(&[], &[("TrackingEvents", &TRACKING_EVENTS), ("NonLinear", &NON_LINEAR)]);
+static NON_LINEAR: Element = element(
+    &[
+        ("id", T),
+        ("width", T),
+        ("height", T),
+        ("expandedWidth", T),
+        ("expandedHeight", T),
+        ("scalable", B),
+        ("maintainAspectRatio", B),
+        ("minSuggestedDuration", T),
+        ("apiFramework", T),
+    ],
+    &[
+        ("HTMLResource", &TEXT),
+        ("IFrameResource", &TEXT),
+        ("StaticResource", &STATIC_RESOURCE),
+        ("AdParameters", &AD_PARAMETERS),
+        ("NonLinearClickThrough", &TEXT),
+        ("NonLinearClickTracking", &URI),
+    ],
+);
+static COMPANION_ADS: Element =
+    element(&[("required", Value::Enum(&["all", "any", "none"]))], &[("Companion", &COMPANION)]);
+static COMPANION: Element = element(
+    &[
+        ("id", T),
+        ("width", T),
+        ("height", T),
+        ("assetWidth", T),
+        ("assetHeight", T),
+        ("expandedWidth", T),
+        ("expandedHeight", T),
+        ("apiFramework", T),
+        ("adSlotId", T),
+        ("pxratio", T),
+        ("renderingMode", Value::Enum(&["default", "end-card", "concurrent"])),
+    ],
+    &[
+        ("HTMLResource", &TEXT),
+        ("IFrameResource", &TEXT),
+        ("StaticResource", &STATIC_RESOURCE),
+        ("AdParameters", &AD_PARAMETERS),
+        ("AltText", &TEXT),
+        ("CompanionClickThrough", &TEXT),
+        ("CompanionClickTracking", &URI),
+        ("CreativeExtensions", &CREATIVE_EXTENSIONS),
+        ("TrackingEvents", &TRACKING_EVENTS),
+    ],
+);
+static TRACKING_EVENTS: Element = element(&[], &[("Tracking", &TRACKING)]);
+static TRACKING: Element = element(
+    &[
+        (
+            "event",
+            Value::Enum(&[
+                "mute",
+                "unmute",
+                "pause",
+                "resume",
+                "rewind",
+                "skip",
+                "playerExpand",
+                "playerCollapse",
+                "loaded",
+                "start",
+                "firstQuartile",
+                "midpoint",
+                "thirdQuartile",
+                "complete",
+                "progress",
+                "closeLinear",
+                "creativeView",
+                "acceptInvitation",
+                "adExpand",
+                "adCollapse",
+                "minimize",
+                "close",
+                "overlayViewDuration",
+                "otherAdInteraction",
+                "interactiveStart",
+            ]),
+        ),
+        ("offset", T),
+    ],
+    &[],
+);
+
+// The elements of VAST 3.0 whose structure differs from VAST 4.x, see [`crate::v3`].
+pub(crate) mod v3 {
+    use super::{element, Element, Value, B, T, TEXT};
+    use super::{AD_PARAMETERS, AD_SYSTEM, CREATIVE_EXTENSIONS, EXTENSIONS, ICONS, MEDIA_FILES};
+    use super::{NON_LINEAR, PRICING, STATIC_RESOURCE, SURVEY, URI, VIDEO_CLICKS};
+
+    /// The root of VAST 3.0.
+    pub(crate) static ROOT: &[(&str, &Element)] = &[("VAST", &VAST)];
+
+    pub(super) static VAST: Element = Element {
+        attributes: super::Attributes::Any,
+        children: &[("Ad", &AD), ("Error", &TEXT)],
+        raw: false,
+    };
+    static AD: Element =
+        element(&[("id", T), ("sequence", T)], &[("InLine", &IN_LINE), ("Wrapper", &WRAPPER)]);
+    static IN_LINE: Element = element(
+        &[],
+        &[
+            ("AdSystem", &AD_SYSTEM),
+            ("AdTitle", &TEXT),
+            ("Description", &TEXT),
+            ("Advertiser", &TEXT),
+            ("Pricing", &PRICING),
+            ("Survey", &SURVEY),
+            ("Error", &TEXT),
+            ("Impression", &URI),
+            ("Creatives", &CREATIVES),
+            ("Extensions", &EXTENSIONS),
+        ],
+    );
+    static WRAPPER: Element = element(
+        &[("followAdditionalWrappers", B), ("allowMultipleAds", B), ("fallbackOnNoAd", B)],
+        &[
+            ("AdSystem", &AD_SYSTEM),
+            ("VASTAdTagURI", &TEXT),
+            ("Error", &TEXT),
+            ("Impression", &URI),
+            ("Creatives", &CREATIVES),
+            ("Extensions", &EXTENSIONS),
+        ],
+    );
+    static CREATIVES: Element = element(&[], &[("Creative", &CREATIVE)]);
+    static CREATIVE: Element = element(
+        &[("id", T), ("sequence", T), ("AdID", T), ("apiFramework", T)],
+        &[
+            ("CreativeExtensions", &CREATIVE_EXTENSIONS),
+            ("Linear", &LINEAR),
+            ("CompanionAds", &COMPANION_ADS),
+            ("NonLinearAds", &NON_LINEAR_ADS),
+        ],
+    );
+    static COMPANION_ADS: Element = element(
+        &[("required", Value::Enum(&["all", "any", "none"]))],
+        &[("Companion", &COMPANION)],
+    );
+    static COMPANION: Element = element(
+        &[
+            ("id", T),
+            ("width", T),
+            ("height", T),
+            ("assetWidth", T),
+            ("assetHeight", T),
+            ("expandedWidth", T),
+            ("expandedHeight", T),
+            ("apiFramework", T),
+            ("adSlotID", T),
+        ],
+        &[
+            ("HTMLResource", &TEXT),
+            ("IFrameResource", &TEXT),
+            ("StaticResource", &STATIC_RESOURCE),
+            ("CreativeExtensions", &CREATIVE_EXTENSIONS),
+            ("TrackingEvents", &TRACKING_EVENTS),
+            ("CompanionClickThrough", &TEXT),
+            ("CompanionClickTracking", &URI),
+            ("AltText", &TEXT),
+            ("AdParameters", &AD_PARAMETERS),
+        ],
+    );
+    static LINEAR: Element = element(
+        &[("skipoffset", T)],
+        &[
+            ("Icons", &ICONS),
+            ("TrackingEvents", &TRACKING_EVENTS),
+            ("AdParameters", &AD_PARAMETERS),
+            ("Duration", &TEXT),
+            ("MediaFiles", &MEDIA_FILES),
+            ("VideoClicks", &VIDEO_CLICKS),
+        ],
+    );
+    static NON_LINEAR_ADS: Element =
+        element(&[], &[("TrackingEvents", &TRACKING_EVENTS), ("NonLinear", &NON_LINEAR)]);
+    static TRACKING_EVENTS: Element = element(&[], &[("Tracking", &TRACKING)]);
+    static TRACKING: Element = element(
+        &[
+            (
+                "event",
+                Value::Enum(&[
+                    "creativeView",
+                    "start",
+                    "firstQuartile",
+                    "midpoint",
+                    "thirdQuartile",
+                    "complete",
+                    "mute",
+                    "unmute",
+                    "pause",
+                    "rewind",
+                    "resume",
+                    "fullscreen",
+                    "exitFullscreen",
+                    "expand",
+                    "collapse",
+                    "acceptInvitation",
+                    "acceptInvitationLinear",
+                    "close",
+                    "closeLinear",
+                    "skip",
+                    "progress",
+                ]),
+            ),
+            ("offset", T),
+        ],
+        &[],
+    );
+}
+
+// The elements of VMAP 1.0, see [`crate::vmap`].
+pub(crate) mod vmap {
+    use super::{element, Attributes, Element, B, T};
+
+    /// The root of VMAP 1.0.
+    pub(crate) static ROOT: &[(&str, &Element)] = &[("vmap:VMAP", &VMAP)];
+
+    pub(super) static VMAP: Element = Element {
+        attributes: Attributes::Any,
+        children: &[("vmap:AdBreak", &AD_BREAK), ("vmap:Extensions", &EXTENSIONS)],
+        raw: false,
+    };
+    static AD_BREAK: Element = element(
+        &[("timeOffset", T), ("breakType", T), ("breakId", T), ("repeatAfter", T)],
+        &[
+            ("vmap:AdSource", &AD_SOURCE),
+            ("vmap:TrackingEvents", &TRACKING_EVENTS),
+            ("vmap:Extensions", &EXTENSIONS),
+        ],
+    );
+    static AD_SOURCE: Element = element(
+        &[("id", T), ("allowMultipleAds", B), ("followRedirects", B)],
+        &[
+            ("vmap:VASTAdData", &VAST_AD_DATA),
+            ("vmap:CustomAdData", &RAW),
+            ("vmap:AdTagURI", &AD_TAG_URI),
+        ],
+    );
+    static VAST_AD_DATA: Element = element(&[], &[("VAST", &super::VAST)]);
+    static AD_TAG_URI: Element = element(&[("templateType", T)], &[]);
+    static TRACKING_EVENTS: Element = element(&[], &[("vmap:Tracking", &TRACKING)]);
+    static TRACKING: Element = element(&[("event", T)], &[]);
+    static EXTENSIONS: Element = element(&[], &[("vmap:Extension", &RAW)]);
+    static RAW: Element = Element { attributes: Attributes::Any, children: &[], raw: true };
+}
+
+#[cfg(test)]
+#[test]
+fn test_enum_values() {
+    // every listed value is parsed by the model, which writes a listed value back
+    fn check<T: std::str::FromStr + std::fmt::Display>(values: &[&str]) {
+        for value in values {
+            let parsed = value.parse::<T>().unwrap_or_else(|_| panic!("'{value}' is not parsed"));
+            assert!(values.contains(&parsed.to_string().as_str()), "'{parsed}' is not listed");
+        }
+    }
+
+    fn collect(
+        name: &'static str,
+        element: &'static Element,
+        enums: &mut Vec<(&'static str, &'static str, &'static [&'static str])>,
+    ) {
+        if let Attributes::Only(attributes) = element.attributes {
+            for &(attribute, value) in attributes {
+                if let Value::Enum(values) = value {
+                    if !enums.iter().any(|&(n, a, _)| n == name && a == attribute) {
+                        enums.push((name, attribute, values));
+                    }
+                }
+            }
+        }
+        for &(name, child) in element.children {
+            if !std::ptr::eq(child, element) {
+                collect(name, child, enums);
+            }
+        }
+    }
+
+    for (root, v3) in [(ROOT, false), (v3::ROOT, true), (vmap::ROOT, false)] {
+        let mut enums = Vec::new();
+        for &(name, element) in root {
+            collect(name, element, &mut enums);
+        }
+        for (name, attribute, values) in enums {
+            match (name, attribute) {
+                ("Pricing", "model") => check::<crate::PriceModel>(values),
+                ("MediaFile" | "Mezzanine", "delivery") => check::<crate::DeliveryProtocol>(values),
+                ("Icon", "xPosition") => check::<crate::XPosition>(values),
+                ("Icon", "yPosition") => check::<crate::YPosition>(values),
+                ("CompanionAds", "required") => check::<crate::CompanionRequirement>(values),
+                ("Companion", "renderingMode") => check::<crate::RenderingMode>(values),
+                ("Tracking", "event") if v3 => check::<crate::v3::TrackingEvent>(values),
+                ("Tracking", "event") => check::<crate::TrackingEvent>(values),
+                _ => panic!("{name} {attribute} is not checked against the model"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_tables() {
+    use std::collections::HashMap;
+
+    // A struct of the model, as declared in its source.
+    #[derive(Default)]
+    struct Model {
+        tag: &'static str,
+        // whether unknown attributes are rejected
+        strict_attributes: bool,
+        // the name and whether the field is a bool
+        attributes: Vec<(&'static str, bool)>,
+        // the name and the type, `None` for a text only element
+        children: Vec<(&'static str, Option<(&'static str, &'static str)>)>,
+    }
+
+    // Reads the structs declared with `#[xml(tag = ...)]` in `source`, by module and name.
+    fn read(
+        module: &'static str,
+        source: &'static str,
+        models: &mut HashMap<(&'static str, &'static str), Model>,
+    ) {
+        let quoted = |s: &'static str| s.split('"').nth(1).unwrap();
+        let mut current: Option<(&'static str, Model)> = None;
+        let mut field: Option<&'static str> = None;
+        for line in source.lines().map(str::trim) {
+            if let Some(xml) = line.strip_prefix("#[xml(") {
+                match current {
+                    Some(_) => field = Some(xml),
+                    None if xml.starts_with("tag = ") => {
+                        let model = Model {
+                            tag: quoted(xml),
+                            strict_attributes: xml.contains("unknown_attribute"),
+                            ..Default::default()
+                        };
+                        current = Some(("", model));
+                    }
+                    None => {}
+                }
+            } else if let Some(name) = line.strip_prefix("pub struct ") {
+                if let Some((ref mut n, _)) = current {
+                    *n = name.split(['<', ' ']).next().unwrap();
+                }
+            } else if let (Some(xml), Some(ty)) = (field, line.strip_prefix("pub ")) {
+                let (_, model) = current.as_mut().unwrap();
+                let ty = ty.split_once(": ").unwrap().1;
+                if xml.starts_with("attr = ") {
+                    model.attributes.push((quoted(xml), ty.contains("bool")));
+                } else if xml.starts_with("flatten_text = ") {
+                    model.children.push((quoted(xml), None));
+                } else if xml.starts_with("child = ") {
+                    let ty = ty.trim_start_matches("Option<").trim_start_matches("Vec<");
+                    let path = ty.split(['<', '>', ',']).next().unwrap();
+                    let (module, name) = match path.rsplit_once("::") {
+                        Some((m, name)) if m.ends_with("v3") => ("v3", name),
+                        Some((m, name)) if m.ends_with("vmap") => ("vmap", name),
+                        Some((_, name)) => ("crate", name),
+                        None => (module, path),
+                    };
+                    model.children.push((quoted(xml), Some((module, name))));
+                }
+                field = None;
+            } else if line == "}" {
+                if let Some((name, model)) = current.take() {
+                    models.insert((module, name), model);
+                }
+            }
+        }
+    }
+
+    fn check(
+        element: &'static Element,
+        model: &Model,
+        models: &HashMap<(&'static str, &'static str), Model>,
+        path: &str,
+    ) {
+        match element.attributes {
+            Attributes::Any => assert!(!model.strict_attributes, "{path} rejects attributes"),
+            Attributes::Only(attributes) => {
+                let mut listed: Vec<_> = attributes
+                    .iter()
+                    .map(|&(name, value)| (name, matches!(value, Value::Bool)))
+                    .collect();
+                let mut declared = model.attributes.clone();
+                listed.sort();
+                declared.sort();
+                assert_eq!(listed, declared, "attributes of {path}");
+            }
+        }
+
+        let mut listed: Vec<_> = element.children.iter().map(|&(name, _)| name).collect();
+        let mut declared: Vec<_> = model.children.iter().map(|&(name, _)| name).collect();
+        listed.sort();
+        declared.sort();
+        assert_eq!(listed, declared, "children of {path}");
+
+        for &(name, ty) in &model.children {
+            let child = element.children.iter().find(|&&(n, _)| n == name).unwrap().1;
+            let path = format!("{path}/{name}");
+            match ty.and_then(|ty| models.get(&ty)) {
+                Some(model) => {
+                    assert_eq!(model.tag, name, "tag of {path}");
+                    if !std::ptr::eq(child, element) {
+                        check(child, model, models, &path);
+                    }
+                }
+                // a text only element, or a type read by hand such as `<Duration>`
+                None => assert!(
+                    std::ptr::eq(child, &TEXT) || child.raw,
+                    "{path} is not a text only element"
+                ),
+            }
+        }
+    }
+
+    let mut models = HashMap::new();
+    for source in [
+        include_str!("ad.rs"),
+        include_str!("click.rs"),
+        include_str!("companion_ad.rs"),
+        include_str!("creative.rs"),
+        include_str!("creative_resource.rs"),
+        include_str!("extension.rs"),
+        include_str!("icon.rs"),
+        include_str!("in_line.rs"),
+        include_str!("linear.rs"),
+        include_str!("media_file.rs"),
+        include_str!("non_linear.rs"),
+        include_str!("tracking.rs"),
+        include_str!("vast.rs"),
+        include_str!("verification.rs"),
+        include_str!("viewable.rs"),
+        include_str!("wrapper.rs"),
+    ] {
+        read("crate", source, &mut models);
+    }
+    read("v3", include_str!("v3.rs"), &mut models);
+    read("vmap", include_str!("vmap.rs"), &mut models);
+
+    check(&VAST, &models[&("crate", "Vast")], &models, "VAST");
+    check(&v3::VAST, &models[&("v3", "Vast")], &models, "VAST");
+    check(&vmap::VMAP, &models[&("vmap", "Vmap")], &models, "vmap:VMAP");
+}
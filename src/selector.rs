@@ -0,0 +1,211 @@
+/// The playback capabilities of the device a [`MediaFile`](crate::MediaFile) is selected for.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct DeviceProfile {
+    /// The supported MIME types, e.g. `video/mp4`, compared as [`MimeType`](crate::MimeType)s.
+    /// Empty means any type is supported.
+    pub mime_types: Vec<String>,
+    /// The supported codecs, either as a full codec string (`avc1.42E01E`) or as a codec family
+    /// (`avc1`), compared with [`Codec::is_supported_by`](crate::Codec::is_supported_by). Empty
+    /// means any codec is supported.
+    pub codecs: Vec<String>,
+    /// The width and height of the screen, in pixels.
+    pub screen_size: Option<(u32, u32)>,
+    /// The estimated bandwidth, in Kbps.
+    pub bandwidth: Option<u32>,
+    /// The preferred delivery protocol.
+    pub delivery: Option<crate::DeliveryProtocol>,
+}
+
+/// The reason a [`MediaFile`](crate::MediaFile) was not considered for playback.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum RejectReason {
+    /// The MIME type of the file is not supported.
+    MimeType(String),
+    /// A codec of the file is not supported.
+    Codec(String),
+    /// The lowest bitrate of the file, in Kbps, exceeds the estimated bandwidth.
+    Bitrate(i32),
+}
+
+/// A [`MediaFile`](crate::MediaFile) that was not considered for playback.
+#[derive(PartialEq, Clone, Debug)]
+pub struct RejectedMediaFile<'b, 'a> {
+    pub media_file: &'b crate::MediaFile<'a>,
+    pub reasons: Vec<RejectReason>,
+}
+
+/// The result of [`MediaFiles::select`](crate::MediaFiles::select).
+#[derive(PartialEq, Clone, Debug)]
+pub struct MediaSelection<'b, 'a> {
+    /// The best playable file.
+    pub media_file: &'b crate::MediaFile<'a>,
+    /// Every playable file, best first.
+    pub candidates: Vec<&'b crate::MediaFile<'a>>,
+    /// The files that can not be played on the device, in document order.
+    pub rejected: Vec<RejectedMediaFile<'b, 'a>>,
+}
+
+/// Represents an error for selecting a media file: none of the files can be played on the device.
+#[derive(PartialEq, Clone, Debug)]
+pub struct SelectError<'b, 'a> {
+    /// The files that can not be played on the device, in document order.
+    pub rejected: Vec<RejectedMediaFile<'b, 'a>>,
+}
+
+impl SelectError<'_, '_> {
+    /// Returns the error code the media player should report for this error.
+    pub fn code(&self) -> crate::VastErrorCode {
+        crate::VastErrorCode::NoSupportedMediaFile
+    }
+}
+
+impl std::fmt::Display for SelectError<'_, '_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "none of the {} media files is supported", self.rejected.len())
+    }
+}
+
+impl std::error::Error for SelectError<'_, '_> {}
+
+impl<'a> crate::MediaFiles<'a> {
+    /// Selects the [`MediaFile`](crate::MediaFile) that suits `profile` the best.
+    ///
+    /// Files whose MIME type or codec is not supported, or whose lowest bitrate exceeds the
+    /// bandwidth, are rejected. The remaining files are ranked by, in order:
+    ///
+    /// 1. the preferred delivery protocol,
+    /// 2. fitting within the screen, then the largest size that fits (or the smallest size that
+    ///    does not),
+    /// 3. the highest bitrate.
+    pub fn select<'b>(
+        &'b self,
+        profile: &DeviceProfile,
+    ) -> Result<MediaSelection<'b, 'a>, SelectError<'b, 'a>> {
+        let mut candidates = Vec::new();
+        let mut rejected = Vec::new();
+        for media_file in &self.media_files {
+            let reasons = profile.reject_reasons(media_file);
+            match reasons.is_empty() {
+                true => candidates.push(media_file),
+                false => rejected.push(RejectedMediaFile { media_file, reasons }),
+            }
+        }
+
+        // stable sort keeps the document order among equally ranked files
+        candidates.sort_by_key(|m| std::cmp::Reverse(profile.rank(m)));
+        match candidates.first() {
+            Some(&media_file) => Ok(MediaSelection { media_file, candidates, rejected }),
+            None => Err(SelectError { rejected }),
+        }
+    }
+}
+
+impl DeviceProfile {
+    fn reject_reasons(&self, media_file: &crate::MediaFile) -> Vec<RejectReason> {
+        let mut reasons = Vec::new();
+
+        let mime_type = media_file.mime();
+        if !self.mime_types.is_empty()
+            && !self
+                .mime_types
+                .iter()
+                .any(|t| t.parse::<crate::MimeType>().as_ref() == Ok(&mime_type))
+        {
+            reasons.push(RejectReason::MimeType(mime_type.to_string()));
+        }
+
+        if let Some(ref codec) = media_file.codec {
+            if !self.codecs.is_empty() {
+                let supported: Vec<crate::Codec> =
+                    self.codecs.iter().filter_map(|c| c.parse().ok()).collect();
+                for codec in codec.split(',').map(str::trim).filter(|c| !c.is_empty()) {
+                    if !codec
+                        .parse::<crate::Codec>()
+                        .is_ok_and(|codec| supported.iter().any(|c| codec.is_supported_by(c)))
+                    {
+                        reasons.push(RejectReason::Codec(codec.to_owned()));
+                    }
+                }
+            }
+        }
+
+        if let (Some(bandwidth), Some(bitrate)) =
+            (self.bandwidth, media_file.min_bitrate.or(media_file.bitrate))
+        {
+            if i64::from(bitrate) > i64::from(bandwidth) {
+                reasons.push(RejectReason::Bitrate(bitrate));
+            }
+        }
+
+        reasons
+    }
+
+    fn rank(&self, media_file: &crate::MediaFile) -> (bool, bool, i64, i32) {
+        let delivery = self.delivery.is_none_or(|d| d == media_file.delivery);
+
+        let area = i64::from(media_file.width) * i64::from(media_file.height);
+        let (fits, size) = match self.screen_size {
+            Some((width, height)) => {
+                let fits = i64::from(media_file.width) <= i64::from(width)
+                    && i64::from(media_file.height) <= i64::from(height);
+                (fits, if fits { area } else { -area })
+            }
+            None => (true, area),
+        };
+
+        let bitrate = media_file.bitrate.or(media_file.max_bitrate).unwrap_or_default();
+        let bitrate = match self.bandwidth {
+            Some(bandwidth) if i64::from(bitrate) > i64::from(bandwidth) => {
+                media_file.min_bitrate.unwrap_or(bitrate)
+            }
+            _ => bitrate,
+        };
+
+        (delivery, fits, size, bitrate)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_select_media_file() {
+    const MEDIA_FILES: &str = r#"<MediaFiles>
+      <MediaFile id="hd" delivery="progressive" type="video/mp4" width="1920" height="1080" bitrate="4000" codec="avc1.640028"><![CDATA[hd]]></MediaFile>
+      <MediaFile id="sd" delivery="progressive" type="video/mp4" width="1280" height="720" bitrate="2000" codec="avc1.4D401F"><![CDATA[sd]]></MediaFile>
+      <MediaFile id="low" delivery="progressive" type="video/mp4" width="640" height="360" bitrate="600" codec="avc1.42E01E"><![CDATA[low]]></MediaFile>
+      <MediaFile id="hls" delivery="streaming" type="application/x-mpegURL" width="1280" height="720" minBitrate="500" maxBitrate="4000"><![CDATA[hls]]></MediaFile>
+      <MediaFile id="webm" delivery="progressive" type="video/webm" width="1280" height="720" bitrate="1500" codec="vp9"><![CDATA[webm]]></MediaFile>
+      <MediaFile id="iab" delivery="progressive" type="video/mp4" width="854" height="480" bitrate="1000" codec="H.264"><![CDATA[iab]]></MediaFile>
+    </MediaFiles>"#;
+
+    fn ids<'b>(media_files: impl IntoIterator<Item = &'b crate::MediaFile<'b>>) -> Vec<String> {
+        media_files.into_iter().map(|m| m.id.as_deref().unwrap_or_default().to_owned()).collect()
+    }
+
+    let media_files = crate::from_str::<crate::MediaFiles>(MEDIA_FILES).unwrap();
+    let mut profile = DeviceProfile {
+        mime_types: vec!["video/mp4".into(), "application/x-mpegURL".into()],
+        codecs: vec!["avc1".into()],
+        screen_size: Some((1280, 800)),
+        bandwidth: Some(2500),
+        delivery: Some(crate::DeliveryProtocol::Progressive),
+    };
+
+    let selection = media_files.select(&profile).unwrap();
+    assert_eq!(selection.media_file.id.as_deref(), Some("sd"));
+    assert_eq!(ids(selection.candidates), ["sd", "iab", "low", "hls"]);
+    assert_eq!(
+        selection.rejected.iter().map(|r| &r.reasons[..]).collect::<Vec<_>>(),
+        [
+            &[RejectReason::Bitrate(4000)][..],
+            &[RejectReason::MimeType("video/webm".into()), RejectReason::Codec("vp9".into())]
+        ]
+    );
+
+    profile.delivery = Some(crate::DeliveryProtocol::Streaming);
+    assert_eq!(media_files.select(&profile).unwrap().media_file.id.as_deref(), Some("hls"));
+
+    profile.mime_types = vec!["video/ogg".into()];
+    let error = media_files.select(&profile).unwrap_err();
+    assert_eq!(error.rejected.len(), 6);
+    assert_eq!(error.code(), crate::VastErrorCode::NoSupportedMediaFile);
+}
@@ -0,0 +1,63 @@
+// Types with a string form in the spec are serialized as that string, with `Display` and
+// `FromStr`.
+macro_rules! impl_serde_str {
+    ($($($ty:ident)::+),* $(,)?) => {
+        $(
+            impl serde::Serialize for $($ty)::+ {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> serde::Deserialize<'de> for $($ty)::+ {
+                fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                    s.parse().map_err(serde::de::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_str!(
+    crate::Duration,
+    crate::AdType,
+    crate::CompanionRequirement,
+    crate::RenderingMode,
+    crate::XPosition,
+    crate::YPosition,
+    crate::PriceModel,
+    crate::DeliveryProtocol,
+    crate::TrackingEvent,
+    crate::v3::TrackingEvent,
+    crate::Offset,
+    crate::VastVersion,
+    crate::vmap::TrackingEvent,
+    crate::vmap::TimeOffset,
+    crate::vmap::BreakType,
+    crate::vmap::BreakTypes,
+);
+
+#[cfg(test)]
+#[test]
+fn test_serde_str() {
+    let json = serde_json::to_string(&crate::Duration::new(0, 1, 2, 300)).unwrap();
+    assert_eq!(json, r#""00:01:02.300""#);
+    assert_eq!(
+        serde_json::from_str::<crate::Duration>(&json).unwrap(),
+        crate::Duration::new(0, 1, 2, 300)
+    );
+
+    let json = serde_json::to_string(&crate::Offset::Percentage(25)).unwrap();
+    assert_eq!(json, r#""25%""#);
+    assert_eq!(
+        serde_json::from_str::<crate::Offset>(&json).unwrap(),
+        crate::Offset::Percentage(25)
+    );
+
+    assert_eq!(
+        serde_json::to_string(&crate::TrackingEvent::FirstQuartile).unwrap(),
+        r#""firstQuartile""#
+    );
+    assert!(serde_json::from_str::<crate::TrackingEvent>(r#""foo""#).is_err());
+}
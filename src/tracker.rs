@@ -0,0 +1,191 @@
+/// Decides which [`<Tracking>`](crate::Tracking) URIs of a [`Linear`](crate::Linear) ad are due,
+/// given the playhead positions and the player events of the ad playback.
+///
+/// Every URI fires at most once, except those of the events the specification allows to repeat
+/// (see [`LinearTracker::is_repeatable`]). Once the ad has been skipped, closed or completed,
+/// nothing fires anymore.
+#[derive(Clone, Debug)]
+pub struct LinearTracker<'a> {
+    duration: Option<std::time::Duration>,
+    trackings: Vec<crate::Tracking<'a>>,
+    fired: Vec<bool>,
+    finished: bool,
+    paused: bool,
+    muted: bool,
+}
+
+impl<'a> LinearTracker<'a> {
+    /// Creates a tracker from the `<Duration>` and the `<TrackingEvents>` of `linear`.
+    pub fn new(linear: &crate::Linear<'a>) -> Self {
+        let trackings =
+            linear.tracking_events.as_ref().map(|t| t.trackings.clone()).unwrap_or_default();
+        Self {
+            duration: linear.duration.clone().map(Into::into),
+            fired: vec![false; trackings.len()],
+            trackings,
+            finished: false,
+            paused: false,
+            muted: false,
+        }
+    }
+
+    /// Sets whether the player starts muted, so the first `mute` event is not reported twice.
+    pub fn with_muted(mut self, muted: bool) -> Self {
+        self.muted = muted;
+        self
+    }
+
+    /// Returns whether the URIs of `event` may fire more than once.
+    pub fn is_repeatable(event: crate::TrackingEvent) -> bool {
+        use crate::TrackingEvent::*;
+        matches!(event, Mute | Unmute | Pause | Resume | Rewind | PlayerExpand | PlayerCollapse)
+    }
+
+    /// Returns whether the ad has been skipped, closed or completed.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Reports the current playhead of the ad. Returns the `start`, quartile, `complete` and
+    /// `progress` trackings that became due.
+    ///
+    /// Quartiles, `complete` and `progress` trackings with a percentage offset are ignored when
+    /// the ad has no `<Duration>`.
+    pub fn update(&mut self, playhead: std::time::Duration) -> Vec<&crate::Tracking<'a>> {
+        use crate::TrackingEvent::*;
+
+        if self.finished {
+            return Vec::new();
+        }
+
+        let mut due = vec![false; self.trackings.len()];
+        for (i, tracking) in self.trackings.iter().enumerate() {
+            due[i] = match tracking.event {
+                Start => true,
+                FirstQuartile => self.reached(playhead, 25.0),
+                MidPoint => self.reached(playhead, 50.0),
+                ThirdQuartile => self.reached(playhead, 75.0),
+                Complete => self.reached(playhead, 100.0),
+                Progress => match tracking.offset {
+                    Some(crate::Offset::Duration(ref offset)) => {
+                        playhead >= std::time::Duration::from(offset.clone())
+                    }
+                    Some(crate::Offset::Percentage(percentage)) => {
+                        self.reached(playhead, f64::from(percentage))
+                    }
+                    None => false,
+                },
+                _ => false,
+            };
+        }
+        if self.duration.is_some() && self.reached(playhead, 100.0) {
+            self.finished = true;
+        }
+
+        self.take(due)
+    }
+
+    /// Reports a player event, e.g. `pause`, `mute`, `skip` or `playerExpand`. Returns the
+    /// trackings of `event` that became due.
+    ///
+    /// `pause`/`resume` and `mute`/`unmute` only fire when they change the state of the player.
+    /// Events driven by the playhead are reported through [`update`](Self::update) instead and
+    /// are ignored here.
+    pub fn on_event(&mut self, event: crate::TrackingEvent) -> Vec<&crate::Tracking<'a>> {
+        use crate::TrackingEvent::*;
+
+        if self.finished {
+            return Vec::new();
+        }
+
+        match event {
+            Start | FirstQuartile | MidPoint | ThirdQuartile | Complete | Progress => {
+                return Vec::new();
+            }
+            Pause | Resume => {
+                if self.paused == (event == Pause) {
+                    return Vec::new();
+                }
+                self.paused = event == Pause;
+            }
+            Mute | Unmute => {
+                if self.muted == (event == Mute) {
+                    return Vec::new();
+                }
+                self.muted = event == Mute;
+            }
+            Skip | CloseLinear => self.finished = true,
+            _ => {}
+        }
+
+        let due = self.trackings.iter().map(|t| t.event == event).collect();
+        self.take(due)
+    }
+
+    fn reached(&self, playhead: std::time::Duration, percentage: f64) -> bool {
+        match self.duration {
+            Some(duration) => playhead.as_secs_f64() >= duration.as_secs_f64() * percentage / 100.0,
+            None => false,
+        }
+    }
+
+    fn take(&mut self, due: Vec<bool>) -> Vec<&crate::Tracking<'a>> {
+        let mut trackings = Vec::new();
+        for (i, tracking) in self.trackings.iter().enumerate() {
+            if !due[i] {
+                continue;
+            }
+            if Self::is_repeatable(tracking.event) {
+                trackings.push(tracking);
+            } else if !self.fired[i] {
+                self.fired[i] = true;
+                trackings.push(tracking);
+            }
+        }
+        trackings
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_linear_tracker() {
+    const LINEAR: &str = r#"<Linear>
+      <TrackingEvents>
+        <Tracking event="start"><![CDATA[start]]></Tracking>
+        <Tracking event="firstQuartile"><![CDATA[firstQuartile]]></Tracking>
+        <Tracking event="midpoint"><![CDATA[midpoint]]></Tracking>
+        <Tracking event="thirdQuartile"><![CDATA[thirdQuartile]]></Tracking>
+        <Tracking event="complete"><![CDATA[complete]]></Tracking>
+        <Tracking event="progress" offset="00:00:05"><![CDATA[progress-5s]]></Tracking>
+        <Tracking event="progress" offset="60%"><![CDATA[progress-60%]]></Tracking>
+        <Tracking event="pause"><![CDATA[pause]]></Tracking>
+        <Tracking event="resume"><![CDATA[resume]]></Tracking>
+        <Tracking event="skip"><![CDATA[skip]]></Tracking>
+      </TrackingEvents>
+      <Duration>00:00:20</Duration>
+    </Linear>"#;
+
+    fn uris(trackings: Vec<&crate::Tracking>) -> Vec<String> {
+        trackings.iter().map(|t| t.uri.to_string()).collect()
+    }
+    let secs = std::time::Duration::from_secs;
+
+    let linear = crate::from_str::<crate::Linear>(LINEAR).unwrap();
+    let mut tracker = LinearTracker::new(&linear);
+    assert_eq!(uris(tracker.update(secs(0))), ["start"]);
+    assert_eq!(uris(tracker.update(secs(6))), ["firstQuartile", "progress-5s"]);
+    assert_eq!(uris(tracker.update(secs(6))), Vec::<String>::new());
+    assert_eq!(uris(tracker.on_event(crate::TrackingEvent::Pause)), ["pause"]);
+    assert_eq!(uris(tracker.on_event(crate::TrackingEvent::Pause)), Vec::<String>::new());
+    assert_eq!(uris(tracker.on_event(crate::TrackingEvent::Resume)), ["resume"]);
+    assert_eq!(uris(tracker.on_event(crate::TrackingEvent::Pause)), ["pause"]);
+    assert_eq!(uris(tracker.update(secs(13))), ["midpoint", "progress-60%"]);
+    assert_eq!(uris(tracker.update(secs(20))), ["thirdQuartile", "complete"]);
+    assert!(tracker.is_finished());
+    assert_eq!(uris(tracker.on_event(crate::TrackingEvent::Skip)), Vec::<String>::new());
+
+    let mut tracker = LinearTracker::new(&linear);
+    assert_eq!(uris(tracker.update(secs(1))), ["start"]);
+    assert_eq!(uris(tracker.on_event(crate::TrackingEvent::Skip)), ["skip"]);
+    assert_eq!(uris(tracker.update(secs(10))), Vec::<String>::new());
+}
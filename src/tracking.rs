@@ -10,6 +10,7 @@
 /// </xs:complexType>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "TrackingEvents", strict(unknown_attribute, unknown_element))]
 pub struct TrackingEvents<'a> {
     /// The container for zero or more [`<Tracking>`](Tracking) elements.
@@ -33,6 +34,7 @@ pub struct TrackingEvents<'a> {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "Tracking", strict(unknown_attribute, unknown_element))]
 pub struct Tracking<'a> {
     /// A string that defines the event being tracked. Accepted values are listed in section
@@ -86,7 +88,7 @@ pub struct Tracking<'a> {
 ///   </xs:restriction>
 /// </xs:simpleType>
 /// ```
-#[derive(PartialEq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum TrackingEvent {
     /// The user activated the mute control and muted the creative.
     Mute,
@@ -0,0 +1,516 @@
+use std::borrow::Cow;
+
+/// The role of a URL in a [`Vast`](crate::Vast) document, i.e. the element holding it.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum UrlRole {
+    /// `<Error>` of `<VAST>`, `<InLine>` or `<Wrapper>`.
+    Error,
+    /// `<Impression>`.
+    Impression,
+    /// `<Viewable>` of `<ViewableImpression>`.
+    Viewable,
+    /// `<NotViewable>` of `<ViewableImpression>`.
+    NotViewable,
+    /// `<ViewUndetermined>` of `<ViewableImpression>`.
+    ViewUndetermined,
+    /// `<VASTAdTagURI>` of `<Wrapper>`.
+    VastAdTagUri,
+    /// `<Survey>`.
+    Survey,
+    /// `<Tracking>` of `<Linear>`, `<NonLinearAds>` or `<Companion>`, with its event.
+    Tracking(crate::TrackingEvent),
+    /// `<ClickThrough>` of `<VideoClicks>`.
+    ClickThrough,
+    /// `<ClickTracking>` of `<VideoClicks>`.
+    ClickTracking,
+    /// `<CustomClick>` of `<VideoClicks>`.
+    CustomClick,
+    /// `<MediaFile>`.
+    MediaFile,
+    /// `<Mezzanine>`.
+    Mezzanine,
+    /// `<InteractiveCreativeFile>`.
+    InteractiveCreativeFile,
+    /// `<ClosedCaptionFile>`.
+    ClosedCaptionFile,
+    /// `<StaticResource>` of `<Icon>`, `<NonLinear>` or `<Companion>`.
+    StaticResource,
+    /// `<IFrameResource>` of `<Icon>`, `<NonLinear>` or `<Companion>`.
+    IFrameResource,
+    /// `<IconClickThrough>`.
+    IconClickThrough,
+    /// `<IconClickTracking>`.
+    IconClickTracking,
+    /// `<StaticResource>` of `<IconClickFallbackImage>`.
+    IconClickFallbackImage,
+    /// `<IconViewTracking>`.
+    IconViewTracking,
+    /// `<NonLinearClickThrough>`.
+    NonLinearClickThrough,
+    /// `<NonLinearClickTracking>`.
+    NonLinearClickTracking,
+    /// `<CompanionClickThrough>`.
+    CompanionClickThrough,
+    /// `<CompanionClickTracking>`.
+    CompanionClickTracking,
+    /// `<JavaScriptResource>` of `<Verification>`.
+    JavaScriptResource,
+    /// `<ExecutableResource>` of `<Verification>`.
+    ExecutableResource,
+    /// `<Tracking>` of `<Verification>`.
+    VerificationTracking,
+}
+
+impl std::fmt::Display for UrlRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Impression => write!(f, "impression"),
+            Self::Viewable => write!(f, "viewable"),
+            Self::NotViewable => write!(f, "not-viewable"),
+            Self::ViewUndetermined => write!(f, "view-undetermined"),
+            Self::VastAdTagUri => write!(f, "vast-ad-tag-uri"),
+            Self::Survey => write!(f, "survey"),
+            Self::Tracking(event) => write!(f, "tracking:{event}"),
+            Self::ClickThrough => write!(f, "click-through"),
+            Self::ClickTracking => write!(f, "click-tracking"),
+            Self::CustomClick => write!(f, "custom-click"),
+            Self::MediaFile => write!(f, "media-file"),
+            Self::Mezzanine => write!(f, "mezzanine"),
+            Self::InteractiveCreativeFile => write!(f, "interactive-creative-file"),
+            Self::ClosedCaptionFile => write!(f, "closed-caption-file"),
+            Self::StaticResource => write!(f, "static-resource"),
+            Self::IFrameResource => write!(f, "iframe-resource"),
+            Self::IconClickThrough => write!(f, "icon-click-through"),
+            Self::IconClickTracking => write!(f, "icon-click-tracking"),
+            Self::IconClickFallbackImage => write!(f, "icon-click-fallback-image"),
+            Self::IconViewTracking => write!(f, "icon-view-tracking"),
+            Self::NonLinearClickThrough => write!(f, "non-linear-click-through"),
+            Self::NonLinearClickTracking => write!(f, "non-linear-click-tracking"),
+            Self::CompanionClickThrough => write!(f, "companion-click-through"),
+            Self::CompanionClickTracking => write!(f, "companion-click-tracking"),
+            Self::JavaScriptResource => write!(f, "verification-javascript-resource"),
+            Self::ExecutableResource => write!(f, "verification-executable-resource"),
+            Self::VerificationTracking => write!(f, "verification-tracking"),
+        }
+    }
+}
+
+/// Visits every URL of a document, see [`Vast::visit_urls`](crate::Vast::visit_urls).
+///
+/// It is implemented by closures taking the same arguments as [`visit_url`](Self::visit_url).
+pub trait UrlVisitor {
+    /// Visits `url`, held by the element at `path`, e.g.
+    /// `Ad[0]/InLine/Creatives/Creative[0]/Linear/TrackingEvents/Tracking[2]`.
+    fn visit_url(&mut self, role: UrlRole, path: &str, url: &str);
+}
+
+impl<F: FnMut(UrlRole, &str, &str)> UrlVisitor for F {
+    fn visit_url(&mut self, role: UrlRole, path: &str, url: &str) {
+        self(role, path, url)
+    }
+}
+
+/// Visits every URL of a document and may rewrite it, see
+/// [`Vast::visit_urls_mut`](crate::Vast::visit_urls_mut).
+///
+/// It is implemented by closures taking the same arguments as
+/// [`visit_url_mut`](Self::visit_url_mut).
+pub trait UrlVisitorMut {
+    /// Visits `url`, held by the element at `path`. Assigning to `url` rewrites the URL.
+    fn visit_url_mut(&mut self, role: UrlRole, path: &str, url: &mut Cow<'_, str>);
+}
+
+impl<F: FnMut(UrlRole, &str, &mut Cow<'_, str>)> UrlVisitorMut for F {
+    fn visit_url_mut(&mut self, role: UrlRole, path: &str, url: &mut Cow<'_, str>) {
+        self(role, path, url)
+    }
+}
+
+impl crate::Vast<'_> {
+    /// Calls `visitor` with every URL of the document, in document order.
+    ///
+    /// ```
+    /// let xml = include_str!("../tests/data/v4_2/Inline_Simple.xml");
+    /// let vast = vast4::from_str::<vast4::Vast>(xml).unwrap();
+    /// let mut urls = Vec::new();
+    /// vast.visit_urls(&mut |role: vast4::UrlRole, _: &str, url: &str| {
+    ///     urls.push(format!("{role} {}", url.trim()))
+    /// });
+    /// assert_eq!(urls[..2], ["error https://example.com/error", "impression https://example.com/track/impression"]);
+    /// ```
+    pub fn visit_urls(&self, visitor: &mut impl UrlVisitor) {
+        Walker { visitor }.vast(self)
+    }
+
+    /// Calls `visitor` with every URL of the document, in document order, to rewrite them.
+    ///
+    /// ```
+    /// # use std::borrow::Cow;
+    /// let xml = include_str!("../tests/data/v4_2/Inline_Simple.xml");
+    /// let mut vast = vast4::from_str::<vast4::Vast>(xml).unwrap();
+    /// vast.visit_urls_mut(&mut |_: vast4::UrlRole, _: &str, url: &mut Cow<str>| {
+    ///     if let Some(rest) = url.strip_prefix("http://") {
+    ///         *url = format!("https://{rest}").into();
+    ///     }
+    /// });
+    /// ```
+    pub fn visit_urls_mut(&mut self, visitor: &mut impl UrlVisitorMut) {
+        WalkerMut { visitor }.vast(self)
+    }
+}
+
+// Declares a walker over the document, calling `$visit` of `$visitor` with each URL. The walker
+// over mutable references is declared with `mut`.
+macro_rules! walker {
+    ($walker:ident, $visitor:ident, $visit:ident $(, $mut:tt)?) => {
+        struct $walker<'v, V> {
+            visitor: &'v mut V,
+        }
+
+        impl<V: $visitor> $walker<'_, V> {
+            #[allow(clippy::ptr_arg)]
+            fn url(&mut self, role: UrlRole, path: &str, url: &$($mut)? Cow<'_, str>) {
+                self.visitor.$visit(role, path, url);
+            }
+
+            fn urls(
+                &mut self,
+                role: UrlRole,
+                path: &str,
+                tag: &str,
+                urls: &$($mut)? [Cow<'_, str>],
+            ) {
+                for (i, url) in urls.into_iter().enumerate() {
+                    self.url(role, &format!("{path}/{tag}[{i}]"), url);
+                }
+            }
+
+            fn vast(&mut self, vast: &$($mut)? crate::Vast<'_>) {
+                for (i, url) in (&$($mut)? vast.errors).into_iter().enumerate() {
+                    self.url(UrlRole::Error, &format!("Error[{i}]"), url);
+                }
+                for (i, ad) in (&$($mut)? vast.ads).into_iter().enumerate() {
+                    let path = format!("Ad[{i}]");
+                    if let Some(in_line) = &$($mut)? ad.in_line {
+                        self.in_line(in_line, &format!("{path}/InLine"));
+                    }
+                    if let Some(wrapper) = &$($mut)? ad.wrapper {
+                        self.wrapper(wrapper, &format!("{path}/Wrapper"));
+                    }
+                }
+            }
+
+            fn in_line(&mut self, in_line: &$($mut)? crate::InLine<'_>, path: &str) {
+                self.urls(UrlRole::Error, path, "Error", &$($mut)? in_line.errors);
+                self.impressions(&$($mut)? in_line.impressions, path);
+                if let Some(viewable_impression) = &$($mut)? in_line.viewable_impression {
+                    self.viewable_impression(viewable_impression, path);
+                }
+                if let Some(ad_verifications) = &$($mut)? in_line.ad_verifications {
+                    self.ad_verifications(ad_verifications, path);
+                }
+                if let Some(survey) = &$($mut)? in_line.surveys {
+                    self.url(UrlRole::Survey, &format!("{path}/Survey"), &$($mut)? survey.uri);
+                }
+                let creatives = &$($mut)? in_line.creatives.creatives;
+                for (i, creative) in creatives.into_iter().enumerate() {
+                    self.creative(creative, &format!("{path}/Creatives/Creative[{i}]"));
+                }
+            }
+
+            fn wrapper(&mut self, wrapper: &$($mut)? crate::Wrapper<'_>, path: &str) {
+                self.urls(UrlRole::Error, path, "Error", &$($mut)? wrapper.errors);
+                self.impressions(&$($mut)? wrapper.impressions, path);
+                if let Some(viewable_impression) = &$($mut)? wrapper.viewable_impression {
+                    self.viewable_impression(viewable_impression, path);
+                }
+                if let Some(ad_verifications) = &$($mut)? wrapper.ad_verifications {
+                    self.ad_verifications(ad_verifications, path);
+                }
+                if let Some(creatives) = &$($mut)? wrapper.creatives {
+                    for (i, creative) in (&$($mut)? creatives.creatives).into_iter().enumerate() {
+                        self.creative(creative, &format!("{path}/Creatives/Creative[{i}]"));
+                    }
+                }
+                let uri = &$($mut)? wrapper.vast_ad_tag_uri;
+                self.url(UrlRole::VastAdTagUri, &format!("{path}/VASTAdTagURI"), uri);
+            }
+
+            fn impressions(&mut self, impressions: &$($mut)? [crate::Impression<'_>], path: &str) {
+                for (i, impression) in impressions.into_iter().enumerate() {
+                    let path = format!("{path}/Impression[{i}]");
+                    self.url(UrlRole::Impression, &path, &$($mut)? impression.uri);
+                }
+            }
+
+            fn viewable_impression(
+                &mut self,
+                viewable_impression: &$($mut)? crate::ViewableImpression<'_>,
+                path: &str,
+            ) {
+                let path = format!("{path}/ViewableImpression");
+                let v = viewable_impression;
+                self.urls(UrlRole::Viewable, &path, "Viewable", &$($mut)? v.viewables);
+                self.urls(UrlRole::NotViewable, &path, "NotViewable", &$($mut)? v.not_viewables);
+                let view_undetermineds = &$($mut)? v.view_undetermineds;
+                self.urls(UrlRole::ViewUndetermined, &path, "ViewUndetermined", view_undetermineds);
+            }
+
+            fn ad_verifications(
+                &mut self,
+                ad_verifications: &$($mut)? crate::AdVerifications<'_>,
+                path: &str,
+            ) {
+                let verifications = &$($mut)? ad_verifications.verifications;
+                for (i, verification) in verifications.into_iter().enumerate() {
+                    let path = format!("{path}/AdVerifications/Verification[{i}]");
+                    let resources = &$($mut)? verification.javascript_resources;
+                    for (j, resource) in resources.into_iter().enumerate() {
+                        let path = format!("{path}/JavaScriptResource[{j}]");
+                        self.url(UrlRole::JavaScriptResource, &path, &$($mut)? resource.uri);
+                    }
+                    let resources = &$($mut)? verification.executable_resources;
+                    for (j, resource) in resources.into_iter().enumerate() {
+                        let path = format!("{path}/ExecutableResource[{j}]");
+                        self.url(UrlRole::ExecutableResource, &path, &$($mut)? resource.uri);
+                    }
+                    if let Some(tracking_events) = &$($mut)? verification.tracking_events {
+                        let trackings = &$($mut)? tracking_events.trackings;
+                        for (j, tracking) in trackings.into_iter().enumerate() {
+                            let path = format!("{path}/TrackingEvents/Tracking[{j}]");
+                            self.url(UrlRole::VerificationTracking, &path, &$($mut)? tracking.uri);
+                        }
+                    }
+                }
+            }
+
+            fn creative(&mut self, creative: &$($mut)? crate::Creative<'_>, path: &str) {
+                if let Some(linear) = &$($mut)? creative.linear {
+                    self.linear(linear, &format!("{path}/Linear"));
+                }
+                if let Some(non_linear_ads) = &$($mut)? creative.non_linear_ads {
+                    self.non_linear_ads(non_linear_ads, &format!("{path}/NonLinearAds"));
+                }
+                if let Some(companion_ads) = &$($mut)? creative.companion_ads {
+                    let companions = &$($mut)? companion_ads.companions;
+                    for (i, companion) in companions.into_iter().enumerate() {
+                        self.companion(companion, &format!("{path}/CompanionAds/Companion[{i}]"));
+                    }
+                }
+            }
+
+            fn tracking_events(
+                &mut self,
+                tracking_events: &$($mut)? Option<crate::TrackingEvents<'_>>,
+                path: &str,
+            ) {
+                if let Some(tracking_events) = tracking_events {
+                    let trackings = &$($mut)? tracking_events.trackings;
+                    for (i, tracking) in trackings.into_iter().enumerate() {
+                        let path = format!("{path}/TrackingEvents/Tracking[{i}]");
+                        self.url(UrlRole::Tracking(tracking.event), &path, &$($mut)? tracking.uri);
+                    }
+                }
+            }
+
+            fn resources(
+                &mut self,
+                static_resources: &$($mut)? [crate::StaticResource<'_>],
+                iframe_resources: &$($mut)? [Cow<'_, str>],
+                path: &str,
+            ) {
+                for (i, resource) in static_resources.into_iter().enumerate() {
+                    let path = format!("{path}/StaticResource[{i}]");
+                    self.url(UrlRole::StaticResource, &path, &$($mut)? resource.uri);
+                }
+                self.urls(UrlRole::IFrameResource, path, "IFrameResource", iframe_resources);
+            }
+
+            fn linear(&mut self, linear: &$($mut)? crate::Linear<'_>, path: &str) {
+                if let Some(icons) = &$($mut)? linear.icons {
+                    for (i, icon) in (&$($mut)? icons.icons).into_iter().enumerate() {
+                        self.icon(icon, &format!("{path}/Icons/Icon[{i}]"));
+                    }
+                }
+                self.tracking_events(&$($mut)? linear.tracking_events, path);
+                if let Some(media_files) = &$($mut)? linear.media_files {
+                    self.media_files(media_files, &format!("{path}/MediaFiles"));
+                }
+                if let Some(video_clicks) = &$($mut)? linear.video_clicks {
+                    let path = format!("{path}/VideoClicks");
+                    let click_trackings = &$($mut)? video_clicks.click_trackings;
+                    for (i, click) in click_trackings.into_iter().enumerate() {
+                        let path = format!("{path}/ClickTracking[{i}]");
+                        self.url(UrlRole::ClickTracking, &path, &$($mut)? click.uri);
+                    }
+                    if let Some(click) = &$($mut)? video_clicks.click_through {
+                        let path = format!("{path}/ClickThrough");
+                        self.url(UrlRole::ClickThrough, &path, &$($mut)? click.uri);
+                    }
+                    let custom_clicks = &$($mut)? video_clicks.custom_clicks;
+                    for (i, click) in custom_clicks.into_iter().enumerate() {
+                        let path = format!("{path}/CustomClick[{i}]");
+                        self.url(UrlRole::CustomClick, &path, &$($mut)? click.uri);
+                    }
+                }
+            }
+
+            fn media_files(&mut self, media_files: &$($mut)? crate::MediaFiles<'_>, path: &str) {
+                if let Some(files) = &$($mut)? media_files.closed_caption_files {
+                    let files = &$($mut)? files.closed_caption_files;
+                    for (i, file) in files.into_iter().enumerate() {
+                        let path = format!("{path}/ClosedCaptionFiles/ClosedCaptionFile[{i}]");
+                        self.url(UrlRole::ClosedCaptionFile, &path, &$($mut)? file.uri);
+                    }
+                }
+                for (i, file) in (&$($mut)? media_files.media_files).into_iter().enumerate() {
+                    let path = format!("{path}/MediaFile[{i}]");
+                    self.url(UrlRole::MediaFile, &path, &$($mut)? file.uri);
+                }
+                for (i, file) in (&$($mut)? media_files.mezzanines).into_iter().enumerate() {
+                    let path = format!("{path}/Mezzanine[{i}]");
+                    self.url(UrlRole::Mezzanine, &path, &$($mut)? file.uri);
+                }
+                let files = &$($mut)? media_files.interactive_creative_files;
+                for (i, file) in files.into_iter().enumerate() {
+                    let path = format!("{path}/InteractiveCreativeFile[{i}]");
+                    self.url(UrlRole::InteractiveCreativeFile, &path, &$($mut)? file.uri);
+                }
+            }
+
+            fn icon(&mut self, icon: &$($mut)? crate::Icon<'_>, path: &str) {
+                self.resources(
+                    &$($mut)? icon.static_resources,
+                    &$($mut)? icon.iframe_resources,
+                    path,
+                );
+                if let Some(icon_clicks) = &$($mut)? icon.icon_clicks {
+                    let path = format!("{path}/IconClicks");
+                    if let Some(images) = &$($mut)? icon_clicks.icon_click_fallback_images {
+                        let images = &$($mut)? images.icon_click_fallback_images;
+                        for (i, image) in images.into_iter().enumerate() {
+                            if let Some(uri) = &$($mut)? image.static_resource {
+                                let path = format!(
+                                    "{path}/IconClickFallbackImages/IconClickFallbackImage[{i}]/StaticResource"
+                                );
+                                self.url(UrlRole::IconClickFallbackImage, &path, uri);
+                            }
+                        }
+                    }
+                    if let Some(uri) = &$($mut)? icon_clicks.icon_click_through {
+                        self.url(UrlRole::IconClickThrough, &format!("{path}/IconClickThrough"), uri);
+                    }
+                    let trackings = &$($mut)? icon_clicks.icon_click_trackings;
+                    for (i, tracking) in trackings.into_iter().enumerate() {
+                        let path = format!("{path}/IconClickTracking[{i}]");
+                        self.url(UrlRole::IconClickTracking, &path, &$($mut)? tracking.uri);
+                    }
+                }
+                let trackings = &$($mut)? icon.icon_view_trackings;
+                self.urls(UrlRole::IconViewTracking, path, "IconViewTracking", trackings);
+            }
+
+            fn non_linear_ads(
+                &mut self,
+                non_linear_ads: &$($mut)? crate::NonLinearAds<'_>,
+                path: &str,
+            ) {
+                self.tracking_events(&$($mut)? non_linear_ads.tracking_events, path);
+                let non_linears = &$($mut)? non_linear_ads.non_linears;
+                for (i, non_linear) in non_linears.into_iter().enumerate() {
+                    let path = format!("{path}/NonLinear[{i}]");
+                    self.resources(
+                        &$($mut)? non_linear.static_resources,
+                        &$($mut)? non_linear.iframe_resources,
+                        &path,
+                    );
+                    if let Some(uri) = &$($mut)? non_linear.non_linear_click_through {
+                        let path = format!("{path}/NonLinearClickThrough");
+                        self.url(UrlRole::NonLinearClickThrough, &path, uri);
+                    }
+                    let trackings = &$($mut)? non_linear.non_linear_click_trackings;
+                    for (j, tracking) in trackings.into_iter().enumerate() {
+                        let path = format!("{path}/NonLinearClickTracking[{j}]");
+                        self.url(UrlRole::NonLinearClickTracking, &path, &$($mut)? tracking.uri);
+                    }
+                }
+            }
+
+            fn companion(&mut self, companion: &$($mut)? crate::Companion<'_>, path: &str) {
+                self.resources(
+                    &$($mut)? companion.static_resources,
+                    &$($mut)? companion.iframe_resources,
+                    path,
+                );
+                if let Some(uri) = &$($mut)? companion.companion_click_through {
+                    let path = format!("{path}/CompanionClickThrough");
+                    self.url(UrlRole::CompanionClickThrough, &path, uri);
+                }
+                let trackings = &$($mut)? companion.companion_click_trackings;
+                for (i, tracking) in trackings.into_iter().enumerate() {
+                    let path = format!("{path}/CompanionClickTracking[{i}]");
+                    self.url(UrlRole::CompanionClickTracking, &path, &$($mut)? tracking.uri);
+                }
+                self.tracking_events(&$($mut)? companion.tracking_events, path);
+            }
+        }
+    };
+}
+
+walker!(Walker, UrlVisitor, visit_url);
+walker!(WalkerMut, UrlVisitorMut, visit_url_mut, mut);
+
+#[cfg(test)]
+#[test]
+fn test_visit_urls() {
+    let xml = include_str!("../tests/data/v4_2/Inline_Companion_Tag-test.xml");
+    let mut vast = crate::from_str::<crate::Vast>(xml).unwrap();
+
+    let mut urls = Vec::new();
+    vast.visit_urls(&mut |role: UrlRole, path: &str, url: &str| {
+        urls.push((role, path.to_owned(), url.trim().to_owned()))
+    });
+    let creative = "Ad[0]/InLine/Creatives/Creative[0]";
+    assert_eq!(
+        urls[..4],
+        [
+            (
+                UrlRole::Error,
+                "Ad[0]/InLine/Error[0]".to_owned(),
+                "https://example.com/error".to_owned()
+            ),
+            (
+                UrlRole::Impression,
+                "Ad[0]/InLine/Impression[0]".to_owned(),
+                "https://example.com/track/impression".to_owned()
+            ),
+            (
+                UrlRole::StaticResource,
+                format!("{creative}/CompanionAds/Companion[0]/StaticResource[0]"),
+                "https://www.iab.com/wp-content/uploads/2014/09/iab-tech-lab-6-644x290.png"
+                    .to_owned()
+            ),
+            (
+                UrlRole::CompanionClickThrough,
+                format!("{creative}/CompanionAds/Companion[0]/CompanionClickThrough"),
+                "https://iabtechlab.com".to_owned()
+            ),
+        ]
+    );
+    assert_eq!(urls.iter().filter(|(role, ..)| *role == UrlRole::MediaFile).count(), 3);
+    assert_eq!(UrlRole::Tracking(crate::TrackingEvent::Start).to_string(), "tracking:start");
+
+    let mut count = 0;
+    vast.visit_urls_mut(&mut |role: UrlRole, _: &str, url: &mut Cow<str>| {
+        if role == UrlRole::Tracking(crate::TrackingEvent::Progress) {
+            *url = url.replace("http://", "https://").into();
+            count += 1;
+        }
+    });
+    assert_eq!(count, 1);
+    let in_line = vast.ads[0].in_line.as_ref().unwrap();
+    let linear = in_line.creatives.creatives[1].linear.as_ref().unwrap();
+    let trackings = &linear.tracking_events.as_ref().unwrap().trackings;
+    assert_eq!(trackings[1].uri, "https://example.com/tracking/progress-10");
+}
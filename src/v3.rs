@@ -0,0 +1,863 @@
+//! An implementation of [`VAST 3.0`].
+//!
+//! Only the elements whose structure differs from VAST 4.x are defined here, everything else
+//! reuses the types of the crate root. The [`From`] implementations convert a VAST 3.0 document
+//! into the VAST 4.x model, so the rest of the crate (resolver, validator, ...) can be used with it.
+//!
+//! [`VAST 3.0`]: https://www.iab.com/wp-content/uploads/2015/06/VASTv3_0.pdf
+
+/// IAB VAST 3.0 (Video Ad Serving Template).
+///
+/// ```text
+/// <xs:element name="VAST">
+///   <xs:complexType>
+///     <xs:sequence>
+///       <xs:element name="Ad" minOccurs="0" maxOccurs="unbounded">
+///       <xs:element name="Error" minOccurs="0" maxOccurs="1" type="xs:anyURI">
+///     </xs:sequence>
+///     <xs:attribute name="version" type="xs:string" use="required">
+///   </xs:complexType>
+/// </xs:element>
+/// ```
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "VAST", strict(unknown_element))]
+pub struct Vast<'a> {
+    /// The version of the VAST spec (should be "3.0").
+    #[xml(attr = "version")]
+    pub version: std::borrow::Cow<'a, str>,
+
+    /// Top-level element, wraps each ad in the response.
+    #[xml(child = "Ad", default)]
+    pub ads: Vec<Ad<'a>>,
+    /// Used when there is no ad response.
+    #[xml(flatten_text = "Error", cdata, default)]
+    pub errors: Vec<std::borrow::Cow<'a, str>>,
+}
+
+/// Top-level element, wraps each ad in the response.
+///
+/// ```text
+/// <xs:element name="Ad">
+///   <xs:complexType>
+///     <xs:choice>
+///       <xs:element name="InLine" type="InLine_type">
+///       <xs:element name="Wrapper" type="Wrapper_type">
+///     </xs:choice>
+///     <xs:attribute name="id" type="xs:string" use="optional">
+///     <xs:attribute name="sequence" type="xs:integer" use="optional">
+///   </xs:complexType>
+/// </xs:element>
+/// ```
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "Ad", strict(unknown_attribute, unknown_element))]
+pub struct Ad<'a> {
+    /// An ad server-defined identifier string for the ad.
+    #[xml(attr = "id", default)]
+    pub id: Option<std::borrow::Cow<'a, str>>,
+    /// Identifies the sequence of multiple Ads that are part of an Ad Pod.
+    #[xml(attr = "sequence", default)]
+    pub sequence: Option<i32>,
+
+    /// Second-level element surrounding complete ad data for a single ad.
+    #[xml(child = "InLine", default)]
+    pub in_line: Option<InLine<'a>>,
+    /// Second-level element surrounding wrapper ad pointing to Secondary ad server.
+    #[xml(child = "Wrapper", default)]
+    pub wrapper: Option<Wrapper<'a>>,
+}
+
+/// Second-level element surrounding complete ad data for a single ad.
+///
+/// ```text
+/// <xs:complexType name="InLine_type">
+///   <xs:sequence>
+///     <xs:element name="AdSystem" minOccurs="1" maxOccurs="1">
+///     <xs:element name="AdTitle" minOccurs="1" maxOccurs="1" type="xs:string">
+///     <xs:element name="Description" minOccurs="0" maxOccurs="1" type="xs:string">
+///     <xs:element name="Advertiser" minOccurs="0" maxOccurs="1" type="xs:string">
+///     <xs:element name="Pricing" minOccurs="0" maxOccurs="1">
+///     <xs:element name="Survey" minOccurs="0" maxOccurs="1" type="xs:anyURI">
+///     <xs:element name="Error" minOccurs="0" maxOccurs="1" type="xs:anyURI">
+///     <xs:element name="Impression" minOccurs="1" maxOccurs="unbounded" type="Impression_type">
+///     <xs:element name="Creatives" minOccurs="1" maxOccurs="1">
+///     <xs:element name="Extensions" minOccurs="0" maxOccurs="1">
+///   </xs:sequence>
+/// </xs:complexType>
+/// ```
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "InLine", strict(unknown_attribute, unknown_element))]
+pub struct InLine<'a> {
+    /// The name of the ad server that returned the ad.
+    #[xml(child = "AdSystem")]
+    pub ad_system: crate::AdSystem<'a>,
+    /// The common name of the ad.
+    #[xml(flatten_text = "AdTitle")]
+    pub ad_title: std::borrow::Cow<'a, str>,
+    /// A longer description of the ad.
+    #[xml(flatten_text = "Description", default)]
+    pub description: Option<std::borrow::Cow<'a, str>>,
+    /// The name of the advertiser as defined by the ad serving party.
+    #[xml(flatten_text = "Advertiser", default)]
+    pub advertiser: Option<std::borrow::Cow<'a, str>>,
+    /// A value that represents a price that can be used in real-time bidding systems.
+    #[xml(child = "Pricing", default)]
+    pub pricing: Option<crate::Pricing<'a>>,
+    /// A URI to any resource relating to an integrated survey.
+    #[xml(child = "Survey", default)]
+    pub survey: Option<crate::Survey<'a>>,
+    /// A URI representing an error-tracking pixel.
+    #[xml(flatten_text = "Error", cdata, default)]
+    pub errors: Vec<std::borrow::Cow<'a, str>>,
+    /// A URI that directs the media player to a tracking resource file that the media player
+    /// must use to notify the ad server when the impression for the ad occurs.
+    #[xml(child = "Impression")]
+    pub impressions: Vec<crate::Impression<'a>>,
+    /// The container for one or more [`<Creative>`](Creative) elements.
+    #[xml(child = "Creatives")]
+    pub creatives: Creatives<'a>,
+    /// XML node for custom extensions, as defined by the ad server.
+    #[xml(child = "Extensions", default)]
+    pub extensions: Option<crate::Extensions>,
+}
+
+/// Second-level element surrounding wrapper ad pointing to Secondary ad server.
+///
+/// ```text
+/// <xs:complexType name="Wrapper_type">
+///   <xs:sequence>
+///     <xs:element name="AdSystem" minOccurs="1" maxOccurs="1">
+///     <xs:element name="VASTAdTagURI" minOccurs="1" maxOccurs="1" type="xs:anyURI">
+///     <xs:element name="Error" minOccurs="0" maxOccurs="1" type="xs:anyURI">
+///     <xs:element name="Impression" minOccurs="1" maxOccurs="unbounded" type="Impression_type">
+///     <xs:element name="Creatives" minOccurs="0" maxOccurs="1">
+///     <xs:element name="Extensions" minOccurs="0" maxOccurs="1">
+///   </xs:sequence>
+///   <xs:attribute name="followAdditionalWrappers" type="xs:boolean" use="optional">
+///   <xs:attribute name="allowMultipleAds" type="xs:boolean" use="optional">
+///   <xs:attribute name="fallbackOnNoAd" type="xs:boolean" use="optional">
+/// </xs:complexType>
+/// ```
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "Wrapper", strict(unknown_attribute, unknown_element))]
+pub struct Wrapper<'a> {
+    /// A Boolean value that identifies whether subsequent wrappers after a requested VAST
+    /// response is allowed.
+    #[xml(attr = "followAdditionalWrappers", default)]
+    pub follow_additional_wrappers: Option<bool>,
+    /// A Boolean value that identifies whether multiple ads are allowed in the requested VAST
+    /// response.
+    #[xml(attr = "allowMultipleAds", default)]
+    pub allow_multiple_ads: Option<bool>,
+    /// A Boolean value that provides instruction for using an available Ad when the requested
+    /// VAST response returns no ads.
+    #[xml(attr = "fallbackOnNoAd", default)]
+    pub fallback_on_no_ad: Option<bool>,
+
+    /// The name of the ad server that returned the ad.
+    #[xml(child = "AdSystem")]
+    pub ad_system: crate::AdSystem<'a>,
+    /// A URI to another VAST response that may be another VAST Wrapper or a VAST InLine ad.
+    #[xml(flatten_text = "VASTAdTagURI")]
+    pub vast_ad_tag_uri: std::borrow::Cow<'a, str>,
+    /// A URI representing an error-tracking pixel.
+    #[xml(flatten_text = "Error", cdata, default)]
+    pub errors: Vec<std::borrow::Cow<'a, str>>,
+    /// A URI to a tracking resource file used to track an impression.
+    #[xml(child = "Impression")]
+    pub impressions: Vec<crate::Impression<'a>>,
+    /// The container for zero or one [`<Creatives>`](Creatives) element.
+    #[xml(child = "Creatives", default)]
+    pub creatives: Option<Creatives<'a>>,
+    /// XML node for custom extensions, as defined by the ad server.
+    #[xml(child = "Extensions", default)]
+    pub extensions: Option<crate::Extensions>,
+}
+
+/// The container for one or more [`<Creative>`](Creative) elements.
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "Creatives", strict(unknown_attribute, unknown_element))]
+pub struct Creatives<'a> {
+    /// The container for one or more [`<Creative>`](Creative) elements.
+    #[xml(child = "Creative")]
+    pub creatives: Vec<Creative<'a>>,
+}
+
+/// Wraps each creative element within an InLine or Wrapper Ad.
+///
+/// ```text
+/// <xs:element name="Creative">
+///   <xs:complexType>
+///     <xs:sequence>
+///       <xs:element name="CreativeExtensions" minOccurs="0" maxOccurs="1">
+///       <xs:choice>
+///         <xs:element name="Linear" type="Linear_type">
+///         <xs:element name="CompanionAds">
+///         <xs:element name="NonLinearAds">
+///       </xs:choice>
+///     </xs:sequence>
+///     <xs:attribute name="id" type="xs:string" use="optional">
+///     <xs:attribute name="sequence" type="xs:integer" use="optional">
+///     <xs:attribute name="AdID" type="xs:string" use="optional">
+///     <xs:attribute name="apiFramework" type="xs:string" use="optional">
+///   </xs:complexType>
+/// </xs:element>
+/// ```
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "Creative", strict(unknown_attribute, unknown_element))]
+pub struct Creative<'a> {
+    /// An ad server-defined identifier for the creative.
+    #[xml(attr = "id", default)]
+    pub id: Option<std::borrow::Cow<'a, str>>,
+    /// The preferred order in which multiple Creatives should be displayed.
+    #[xml(attr = "sequence", default)]
+    pub sequence: Option<i32>,
+    /// Ad-ID for the creative (formerly ISCI).
+    #[xml(attr = "AdID", default)]
+    pub ad_id: Option<std::borrow::Cow<'a, str>>,
+    /// The technology used for any included API.
+    #[xml(attr = "apiFramework", default)]
+    pub api_framework: Option<std::borrow::Cow<'a, str>>,
+
+    /// The container for zero or one [`<CreativeExtensions>`](crate::CreativeExtensions)
+    /// element.
+    #[xml(child = "CreativeExtensions", default)]
+    pub creative_extensions: Option<crate::CreativeExtensions>,
+    /// The container for zero or one [`<Linear>`](Linear) element.
+    #[xml(child = "Linear", default)]
+    pub linear: Option<Linear<'a>>,
+    /// The container for zero or one [`<CompanionAds>`](CompanionAds) element.
+    #[xml(child = "CompanionAds", default)]
+    pub companion_ads: Option<CompanionAds<'a>>,
+    /// The container for zero or one [`<NonLinearAds>`](NonLinearAds) element.
+    #[xml(child = "NonLinearAds", default)]
+    pub non_linear_ads: Option<NonLinearAds<'a>>,
+}
+
+/// The container for zero or more [`<Companion>`](Companion) elements.
+///
+/// ```text
+/// <xs:element name="CompanionAds">
+///   <xs:complexType>
+///     <xs:sequence>
+///       <xs:element name="Companion" minOccurs="0" maxOccurs="unbounded" type="Companion_type">
+///     </xs:sequence>
+///     <xs:attribute name="required" use="optional">
+///   </xs:complexType>
+/// </xs:element>
+/// ```
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "CompanionAds", strict(unknown_attribute, unknown_element))]
+pub struct CompanionAds<'a> {
+    /// How the player should treat a companion ad when multiple are supplied.
+    #[xml(attr = "required", default)]
+    pub required: Option<crate::CompanionRequirement>,
+
+    /// The container for zero or more [`<Companion>`](Companion) elements.
+    #[xml(child = "Companion", default)]
+    pub companions: Vec<Companion<'a>>,
+}
+
+/// Each `<Companion>` element contains the resource and tracking of a single companion ad.
+///
+/// ```text
+/// <xs:complexType name="Companion_type">
+///   <xs:sequence>
+///     <xs:group ref="CreativeResourceNonVideo">
+///     <xs:element name="CreativeExtensions" minOccurs="0" maxOccurs="1">
+///     <xs:element name="TrackingEvents" minOccurs="0" maxOccurs="1">
+///     <xs:element name="CompanionClickThrough" minOccurs="0" maxOccurs="1" type="xs:anyURI">
+///     <xs:element name="CompanionClickTracking" minOccurs="0" maxOccurs="unbounded">
+///     <xs:element name="AltText" minOccurs="0" maxOccurs="1" type="xs:string">
+///     <xs:element name="AdParameters" minOccurs="0" maxOccurs="1">
+///   </xs:sequence>
+///   <xs:attribute name="id" type="xs:string" use="optional">
+///   <xs:attribute name="width" type="xs:integer" use="required">
+///   <xs:attribute name="height" type="xs:integer" use="required">
+///   <xs:attribute name="assetWidth" type="xs:integer" use="optional">
+///   <xs:attribute name="assetHeight" type="xs:integer" use="optional">
+///   <xs:attribute name="expandedWidth" type="xs:integer" use="optional">
+///   <xs:attribute name="expandedHeight" type="xs:integer" use="optional">
+///   <xs:attribute name="apiFramework" type="xs:string" use="optional">
+///   <xs:attribute name="adSlotID" type="xs:string" use="optional">
+/// </xs:complexType>
+/// ```
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "Companion", strict(unknown_attribute, unknown_element))]
+pub struct Companion<'a> {
+    /// An optional identifier for the creative.
+    #[xml(attr = "id", default)]
+    pub id: Option<std::borrow::Cow<'a, str>>,
+    /// The pixel width of the placement slot for which the creative is intended.
+    #[xml(attr = "width")]
+    pub width: i32,
+    /// The pixel height of the placement slot for which the creative is intended.
+    #[xml(attr = "height")]
+    pub height: i32,
+    /// The pixel width of the creative.
+    #[xml(attr = "assetWidth", default)]
+    pub asset_width: Option<i32>,
+    /// The pixel height of the creative.
+    #[xml(attr = "assetHeight", default)]
+    pub asset_height: Option<i32>,
+    /// The maximum pixel width of the creative in its expanded state.
+    #[xml(attr = "expandedWidth", default)]
+    pub expanded_width: Option<i32>,
+    /// The maximum pixel height of the creative in its expanded state.
+    #[xml(attr = "expandedHeight", default)]
+    pub expanded_height: Option<i32>,
+    /// The API necessary to communicate with the creative if available.
+    #[xml(attr = "apiFramework", default)]
+    pub api_framework: Option<std::borrow::Cow<'a, str>>,
+    /// Used to identify desired placement on a publisher’s page.
+    #[xml(attr = "adSlotID", default)]
+    pub ad_slot_id: Option<std::borrow::Cow<'a, str>>,
+
+    /// The container for zero or more `<HTMLResource>` elements.
+    #[xml(flatten_text = "HTMLResource", cdata, default)]
+    pub html_resources: Vec<std::borrow::Cow<'a, str>>,
+    /// The container for zero or more `<IFrameResource>` elements.
+    #[xml(flatten_text = "IFrameResource", cdata, default)]
+    pub iframe_resources: Vec<std::borrow::Cow<'a, str>>,
+    /// The container for zero or more [`<StaticResource>`](crate::StaticResource) elements.
+    #[xml(child = "StaticResource", default)]
+    pub static_resources: Vec<crate::StaticResource<'a>>,
+
+    /// The container for zero or one [`<CreativeExtensions>`](crate::CreativeExtensions)
+    /// element.
+    #[xml(child = "CreativeExtensions", default)]
+    pub creative_extensions: Option<crate::CreativeExtensions>,
+    /// The container for zero or one [`<TrackingEvents>`](TrackingEvents) element.
+    #[xml(child = "TrackingEvents", default)]
+    pub tracking_events: Option<TrackingEvents<'a>>,
+    /// The container for zero or one `<CompanionClickThrough>` element.
+    #[xml(flatten_text = "CompanionClickThrough", cdata, default)]
+    pub companion_click_through: Option<std::borrow::Cow<'a, str>>,
+    /// The container for zero or more [`<CompanionClickTracking>`](CompanionClickTracking)
+    /// elements.
+    #[xml(child = "CompanionClickTracking", default)]
+    pub companion_click_trackings: Vec<CompanionClickTracking<'a>>,
+    /// The container for zero or one `<AltText>` element.
+    #[xml(flatten_text = "AltText", default)]
+    pub alt_text: Option<std::borrow::Cow<'a, str>>,
+    /// The container for zero or one [`<AdParameters>`](crate::AdParameters) element.
+    #[xml(child = "AdParameters", default)]
+    pub ad_parameters: Option<crate::AdParameters<'a>>,
+}
+
+/// A URI to a tracking resource file used to track a companion clickthrough.
+///
+/// ```text
+/// <xs:element name="CompanionClickTracking">
+///   <xs:complexType>
+///     <xs:simpleContent>
+///       <xs:extension base="xs:anyURI">
+///         <xs:attribute name="id" type="xs:string" use="optional">
+///       </xs:extension>
+///     </xs:simpleContent>
+///   </xs:complexType>
+/// </xs:element>
+/// ```
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "CompanionClickTracking", strict(unknown_attribute, unknown_element))]
+pub struct CompanionClickTracking<'a> {
+    /// An id provided by the ad server to track the click in reports.
+    #[xml(attr = "id", default)]
+    pub id: Option<std::borrow::Cow<'a, str>>,
+
+    /// A URI to a tracking resource file used to track a companion clickthrough.
+    #[xml(text, cdata)]
+    pub uri: std::borrow::Cow<'a, str>,
+}
+
+/// Linear Ads are the video formatted ads that play linearly within the streaming content.
+///
+/// ```text
+/// <xs:complexType name="Linear_type">
+///   <xs:sequence>
+///     <xs:element name="Icons" minOccurs="0" maxOccurs="1">
+///     <xs:element name="TrackingEvents" minOccurs="0" maxOccurs="1" type="TrackingEvents_type">
+///     <xs:element name="AdParameters" minOccurs="0" maxOccurs="1" type="AdParameters_type">
+///     <xs:element name="Duration" minOccurs="1" maxOccurs="1" type="xs:time">
+///     <xs:element name="MediaFiles" minOccurs="1" maxOccurs="1">
+///     <xs:element name="VideoClicks" minOccurs="0" maxOccurs="1" type="VideoClicks_type">
+///   </xs:sequence>
+///   <xs:attribute name="skipoffset" use="optional">
+/// </xs:complexType>
+/// ```
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "Linear", strict(unknown_attribute, unknown_element))]
+pub struct Linear<'a> {
+    /// The time at which the ad becomes skippable.
+    #[xml(attr = "skipoffset", default)]
+    pub skipoffset: Option<crate::Duration>,
+    /// The container for zero or one [`<Icons>`](crate::Icons) element.
+    #[xml(child = "Icons", default)]
+    pub icons: Option<crate::Icons<'a>>,
+    /// The container for zero or one [`<TrackingEvents>`](TrackingEvents) element.
+    #[xml(child = "TrackingEvents", default)]
+    pub tracking_events: Option<TrackingEvents<'a>>,
+    /// Data to be passed into the video ad.
+    #[xml(child = "AdParameters", default)]
+    pub ad_parameters: Option<crate::AdParameters<'a>>,
+    /// The approximate duration of the creative.
+    #[xml(child = "Duration", default)]
+    pub duration: Option<crate::Duration>,
+    /// The container for zero or one [`<MediaFiles>`](crate::MediaFiles) element.
+    #[xml(child = "MediaFiles", default)]
+    pub media_files: Option<crate::MediaFiles<'a>>,
+    /// The container for zero or one [`<VideoClicks>`](crate::VideoClicks) element.
+    #[xml(child = "VideoClicks", default)]
+    pub video_clicks: Option<crate::VideoClicks<'a>>,
+}
+
+/// A container for the [`<NonLinear>`](crate::NonLinear) creative files and tracking resources.
+///
+/// ```text
+/// <xs:element name="NonLinearAds">
+///   <xs:complexType>
+///     <xs:sequence>
+///       <xs:element name="NonLinear" minOccurs="0" maxOccurs="unbounded" type="NonLinear_type">
+///       <xs:element name="TrackingEvents" minOccurs="0" maxOccurs="1" type="TrackingEvents_type">
+///     </xs:sequence>
+///   </xs:complexType>
+/// </xs:element>
+/// ```
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "NonLinearAds", strict(unknown_attribute, unknown_element))]
+pub struct NonLinearAds<'a> {
+    /// The container for zero or one [`<TrackingEvents>`](TrackingEvents) element.
+    #[xml(child = "TrackingEvents", default)]
+    pub tracking_events: Option<TrackingEvents<'a>>,
+    /// The container for zero or more [`<NonLinear>`](crate::NonLinear) elements.
+    #[xml(child = "NonLinear", default)]
+    pub non_linears: Vec<crate::NonLinear<'a>>,
+}
+
+/// The container for zero or more [`<Tracking>`](Tracking) elements.
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "TrackingEvents", strict(unknown_attribute, unknown_element))]
+pub struct TrackingEvents<'a> {
+    /// The container for zero or more [`<Tracking>`](Tracking) elements.
+    #[xml(child = "Tracking", default)]
+    pub trackings: Vec<Tracking<'a>>,
+}
+
+/// Each `<Tracking>` element is used to define a single event to be tracked.
+///
+/// ```text
+/// <xs:element name="Tracking">
+///   <xs:complexType>
+///     <xs:simpleContent>
+///       <xs:extension base="xs:anyURI">
+///         <xs:attribute name="event" use="required">
+///         <xs:attribute name="offset" use="optional">
+///       </xs:extension>
+///     </xs:simpleContent>
+///   </xs:complexType>
+/// </xs:element>
+/// ```
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "Tracking", strict(unknown_attribute, unknown_element))]
+pub struct Tracking<'a> {
+    /// The event being tracked.
+    #[xml(attr = "event")]
+    pub event: TrackingEvent,
+    /// The time or percentage of the Linear creative at which the `progress` event fires.
+    #[xml(attr = "offset", default)]
+    pub offset: Option<crate::Offset>,
+
+    /// A URI to the tracking resource for the event.
+    #[xml(text, cdata, default)]
+    pub uri: std::borrow::Cow<'a, str>,
+}
+
+/// The tracking events of VAST 3.0.
+///
+/// ```text
+/// <xs:simpleType>
+///   <xs:restriction base="xs:string">
+///     <xs:enumeration value="creativeView" />
+///     <xs:enumeration value="start" />
+///     <xs:enumeration value="firstQuartile" />
+///     <xs:enumeration value="midpoint" />
+///     <xs:enumeration value="thirdQuartile" />
+///     <xs:enumeration value="complete" />
+///     <xs:enumeration value="mute" />
+///     <xs:enumeration value="unmute" />
+///     <xs:enumeration value="pause" />
+///     <xs:enumeration value="rewind" />
+///     <xs:enumeration value="resume" />
+///     <xs:enumeration value="fullscreen" />
+///     <xs:enumeration value="exitFullscreen" />
+///     <xs:enumeration value="expand" />
+///     <xs:enumeration value="collapse" />
+///     <xs:enumeration value="acceptInvitation" />
+///     <xs:enumeration value="acceptInvitationLinear" />
+///     <xs:enumeration value="close" />
+///     <xs:enumeration value="closeLinear" />
+///     <xs:enumeration value="skip" />
+///     <xs:enumeration value="progress" />
+///   </xs:restriction>
+/// </xs:simpleType>
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum TrackingEvent {
+    /// An individual creative portion of the ad was viewed.
+    CreativeView,
+    /// The creative began playing.
+    Start,
+    /// The creative played for at least 25% of its duration.
+    FirstQuartile,
+    /// The creative played for at least 50% of its duration.
+    MidPoint,
+    /// The creative played for at least 75% of its duration.
+    ThirdQuartile,
+    /// The creative was played to the end.
+    Complete,
+    /// The user muted the creative.
+    Mute,
+    /// The user unmuted the creative.
+    Unmute,
+    /// The user paused the creative.
+    Pause,
+    /// The user rewound the creative.
+    Rewind,
+    /// The user resumed the creative after it had been paused.
+    Resume,
+    /// The user extended the video player to the edges of the viewer’s screen. Converted into
+    /// `playerExpand`.
+    Fullscreen,
+    /// The user reduced the video player to its original size. Converted into
+    /// `playerCollapse`.
+    ExitFullscreen,
+    /// The user expanded the creative. Converted into `adExpand`.
+    Expand,
+    /// The user reduced the creative to its original dimensions. Converted into `adCollapse`.
+    Collapse,
+    /// The user activated a control used to pause streaming content of a NonLinear ad.
+    AcceptInvitation,
+    /// The user activated a control used to pause streaming content, which either expands the
+    /// ad within the player’s viewable area or “takes-over” the streaming content area.
+    /// Converted into `acceptInvitation`.
+    AcceptInvitationLinear,
+    /// The user dispatched the NonLinear ad from the player environment.
+    Close,
+    /// The user closed the linear ad.
+    CloseLinear,
+    /// The user skipped the creative.
+    Skip,
+    /// The creative played up to the `offset` of the tracking.
+    Progress,
+}
+
+impl std::str::FromStr for TrackingEvent {
+    type Err = crate::VastParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "creativeView" => Self::CreativeView,
+            "start" => Self::Start,
+            "firstQuartile" => Self::FirstQuartile,
+            "midpoint" => Self::MidPoint,
+            "thirdQuartile" => Self::ThirdQuartile,
+            "complete" => Self::Complete,
+            "mute" => Self::Mute,
+            "unmute" => Self::Unmute,
+            "pause" => Self::Pause,
+            "rewind" => Self::Rewind,
+            "resume" => Self::Resume,
+            "fullscreen" => Self::Fullscreen,
+            "exitFullscreen" => Self::ExitFullscreen,
+            "expand" => Self::Expand,
+            "collapse" => Self::Collapse,
+            "acceptInvitation" => Self::AcceptInvitation,
+            "acceptInvitationLinear" => Self::AcceptInvitationLinear,
+            "close" => Self::Close,
+            "closeLinear" => Self::CloseLinear,
+            "skip" => Self::Skip,
+            "progress" => Self::Progress,
+            _ => {
+                return Err(crate::VastParseError::new(format!(
+                    "tracking event parsing error: '{s}'",
+                )));
+            }
+        })
+    }
+}
+
+impl std::fmt::Display for TrackingEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CreativeView => write!(f, "creativeView"),
+            Self::Start => write!(f, "start"),
+            Self::FirstQuartile => write!(f, "firstQuartile"),
+            Self::MidPoint => write!(f, "midpoint"),
+            Self::ThirdQuartile => write!(f, "thirdQuartile"),
+            Self::Complete => write!(f, "complete"),
+            Self::Mute => write!(f, "mute"),
+            Self::Unmute => write!(f, "unmute"),
+            Self::Pause => write!(f, "pause"),
+            Self::Rewind => write!(f, "rewind"),
+            Self::Resume => write!(f, "resume"),
+            Self::Fullscreen => write!(f, "fullscreen"),
+            Self::ExitFullscreen => write!(f, "exitFullscreen"),
+            Self::Expand => write!(f, "expand"),
+            Self::Collapse => write!(f, "collapse"),
+            Self::AcceptInvitation => write!(f, "acceptInvitation"),
+            Self::AcceptInvitationLinear => write!(f, "acceptInvitationLinear"),
+            Self::Close => write!(f, "close"),
+            Self::CloseLinear => write!(f, "closeLinear"),
+            Self::Skip => write!(f, "skip"),
+            Self::Progress => write!(f, "progress"),
+        }
+    }
+}
+
+impl<'a> From<Vast<'a>> for crate::Vast<'a> {
+    fn from(value: Vast<'a>) -> Self {
+        Self {
+            version: value.version,
+            ads: value.ads.into_iter().map(Into::into).collect(),
+            errors: value.errors,
+        }
+    }
+}
+
+impl<'a> From<Ad<'a>> for crate::Ad<'a> {
+    fn from(value: Ad<'a>) -> Self {
+        Self {
+            id: value.id,
+            sequence: value.sequence,
+            in_line: value.in_line.map(Into::into),
+            wrapper: value.wrapper.map(Into::into),
+            ..Default::default()
+        }
+    }
+}
+
+/// `<AdServingId>` does not exist in VAST 3.0 and is left out.
+impl<'a> From<InLine<'a>> for crate::InLine<'a> {
+    fn from(value: InLine<'a>) -> Self {
+        Self {
+            ad_system: value.ad_system,
+            ad_title: value.ad_title,
+            description: value.description,
+            advertiser: value.advertiser,
+            pricing: value.pricing,
+            surveys: value.survey,
+            errors: value.errors,
+            impressions: value.impressions,
+            creatives: value.creatives.into(),
+            extensions: value.extensions,
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> From<Wrapper<'a>> for crate::Wrapper<'a> {
+    fn from(value: Wrapper<'a>) -> Self {
+        Self {
+            follow_additional_wrappers: value.follow_additional_wrappers,
+            allow_multiple_ads: value.allow_multiple_ads,
+            fallback_on_no_ad: value.fallback_on_no_ad,
+            ad_system: value.ad_system,
+            vast_ad_tag_uri: value.vast_ad_tag_uri,
+            errors: value.errors,
+            impressions: value.impressions,
+            creatives: value.creatives.map(Into::into),
+            extensions: value.extensions,
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> From<Creatives<'a>> for crate::Creatives<'a> {
+    fn from(value: Creatives<'a>) -> Self {
+        Self { creatives: value.creatives.into_iter().map(Into::into).collect() }
+    }
+}
+
+/// `<UniversalAdId>` does not exist in VAST 3.0 and is left empty.
+impl<'a> From<Creative<'a>> for crate::Creative<'a> {
+    fn from(value: Creative<'a>) -> Self {
+        Self {
+            id: value.id,
+            sequence: value.sequence,
+            ad_id: value.ad_id,
+            api_framework: value.api_framework,
+            creative_extensions: value.creative_extensions,
+            linear: value.linear.map(Into::into),
+            companion_ads: value.companion_ads.map(Into::into),
+            non_linear_ads: value.non_linear_ads.map(Into::into),
+            universal_ad_id: Vec::new(),
+        }
+    }
+}
+
+impl<'a> From<CompanionAds<'a>> for crate::CompanionAds<'a> {
+    fn from(value: CompanionAds<'a>) -> Self {
+        Self {
+            required: value.required,
+            companions: value.companions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<'a> From<Companion<'a>> for crate::Companion<'a> {
+    fn from(value: Companion<'a>) -> Self {
+        Self {
+            id: value.id,
+            width: value.width,
+            height: value.height,
+            asset_width: value.asset_width,
+            asset_height: value.asset_height,
+            expanded_width: value.expanded_width,
+            expanded_height: value.expanded_height,
+            api_framework: value.api_framework,
+            ad_slot_id: value.ad_slot_id,
+            html_resources: value.html_resources,
+            iframe_resources: value.iframe_resources,
+            static_resources: value.static_resources,
+            ad_parameters: value.ad_parameters,
+            alt_text: value.alt_text,
+            companion_click_through: value.companion_click_through,
+            companion_click_trackings: value
+                .companion_click_trackings
+                .into_iter()
+                .map(|t| crate::CompanionClickTracking { id: t.id, uri: t.uri })
+                .collect(),
+            creative_extensiosn: value.creative_extensions,
+            tracking_events: value.tracking_events.map(Into::into),
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> From<Linear<'a>> for crate::Linear<'a> {
+    fn from(value: Linear<'a>) -> Self {
+        Self {
+            skipoffset: value.skipoffset,
+            icons: value.icons,
+            tracking_events: value.tracking_events.map(Into::into),
+            ad_parameters: value.ad_parameters,
+            duration: value.duration,
+            media_files: value.media_files,
+            video_clicks: value.video_clicks,
+        }
+    }
+}
+
+impl<'a> From<NonLinearAds<'a>> for crate::NonLinearAds<'a> {
+    fn from(value: NonLinearAds<'a>) -> Self {
+        Self {
+            tracking_events: value.tracking_events.map(Into::into),
+            non_linears: value.non_linears,
+        }
+    }
+}
+
+impl<'a> From<TrackingEvents<'a>> for crate::TrackingEvents<'a> {
+    fn from(value: TrackingEvents<'a>) -> Self {
+        Self {
+            trackings: value
+                .trackings
+                .into_iter()
+                .map(|t| crate::Tracking { event: t.event.into(), offset: t.offset, uri: t.uri })
+                .collect(),
+        }
+    }
+}
+
+/// The events replaced in VAST 4 are converted into their replacement.
+impl From<TrackingEvent> for crate::TrackingEvent {
+    fn from(value: TrackingEvent) -> Self {
+        match value {
+            TrackingEvent::CreativeView => Self::CreativeView,
+            TrackingEvent::Start => Self::Start,
+            TrackingEvent::FirstQuartile => Self::FirstQuartile,
+            TrackingEvent::MidPoint => Self::MidPoint,
+            TrackingEvent::ThirdQuartile => Self::ThirdQuartile,
+            TrackingEvent::Complete => Self::Complete,
+            TrackingEvent::Mute => Self::Mute,
+            TrackingEvent::Unmute => Self::Unmute,
+            TrackingEvent::Pause => Self::Pause,
+            TrackingEvent::Rewind => Self::Rewind,
+            TrackingEvent::Resume => Self::Resume,
+            TrackingEvent::Fullscreen => Self::PlayerExpand,
+            TrackingEvent::ExitFullscreen => Self::PlayerCollapse,
+            TrackingEvent::Expand => Self::AdExpand,
+            TrackingEvent::Collapse => Self::AdCollapse,
+            TrackingEvent::AcceptInvitation | TrackingEvent::AcceptInvitationLinear => {
+                Self::AcceptInvitation
+            }
+            TrackingEvent::Close => Self::Close,
+            TrackingEvent::CloseLinear => Self::CloseLinear,
+            TrackingEvent::Skip => Self::Skip,
+            TrackingEvent::Progress => Self::Progress,
+        }
+    }
+}
+
+crate::declare_test!(
+    test_companion_click_tracking,
+    CompanionClickTracking,
+    r#"<CompanionClickTracking><![CDATA[https://example.com/click]]></CompanionClickTracking>"#,
+    CompanionClickTracking { id: None, uri: "https://example.com/click".into() }
+);
+
+#[cfg(test)]
+#[test]
+fn test_into_vast() {
+    let vast =
+        crate::from_str::<Vast>(include_str!("../tests/data/v3_0/Inline_Companion_Tag-test.xml"))
+            .unwrap();
+    let vast = crate::Vast::from(vast);
+
+    let in_line = vast.ads[0].in_line.as_ref().unwrap();
+    assert_eq!(in_line.ad_serving_id, None);
+    let companion = &in_line.creatives.creatives[1].companion_ads.as_ref().unwrap().companions[0];
+    assert_eq!(companion.ad_slot_id.as_deref(), Some("3214"));
+    assert_eq!(companion.companion_click_trackings[0].id, None);
+    let xml = crate::to_string(&vast).unwrap();
+    assert!(xml.contains(r#"adSlotId="3214""#));
+    assert!(xml.contains("<CompanionClickTracking><![CDATA["));
+
+    let vast =
+        crate::from_str::<Vast>(include_str!("../tests/data/v3_0/Inline_Linear_Tag-test.xml"))
+            .unwrap();
+    let vast = crate::Vast::from(vast);
+    let linear = vast.ads[0].in_line.as_ref().unwrap().creatives.creatives[0].linear.as_ref();
+    let events: Vec<_> = linear
+        .unwrap()
+        .tracking_events
+        .iter()
+        .flat_map(|t| &t.trackings)
+        .map(|t| t.event)
+        .collect();
+    assert!(events.contains(&crate::TrackingEvent::PlayerExpand));
+    assert!(events.contains(&crate::TrackingEvent::PlayerCollapse));
+
+    for (event, into) in [
+        ("expand", "adExpand"),
+        ("collapse", "adCollapse"),
+        ("acceptInvitationLinear", "acceptInvitation"),
+    ] {
+        let event = event.parse::<TrackingEvent>().unwrap();
+        assert_eq!(crate::TrackingEvent::from(event).to_string(), into);
+    }
+    assert!("playerExpand".parse::<TrackingEvent>().is_err());
+    assert!("fullscreen".parse::<crate::TrackingEvent>().is_err());
+}
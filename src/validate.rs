@@ -0,0 +1,488 @@
+/// The severity of a [`Diagnostic`].
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub enum Severity {
+    /// The document violates a rule the specification recommends.
+    Warning,
+    /// The document violates a rule the specification requires.
+    Error,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Warning => write!(f, "warning"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A violation of a rule of the specification found by [`validate`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// The identifier of the violated rule, e.g. `inline-ad-serving-id`.
+    pub rule: &'static str,
+    /// The section of the specification defining the rule, e.g. `3.4`.
+    pub section: &'static str,
+    /// The path of the offending element, e.g. `Ad[0]/InLine/Creatives/Creative[1]/Linear`.
+    pub path: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}[{}] {}: {} (Sec {})",
+            self.severity, self.rule, self.path, self.message, self.section
+        )
+    }
+}
+
+/// Checks the rules of the specification the XML schema can not express, e.g. an
+/// [`InLine`](crate::InLine) without [`<Impression>`](crate::Impression) or a
+/// [`Companion`](crate::Companion) without any resource.
+///
+/// The elements added in VAST 4.0, such as `<AdServingId>`, are not required from a document
+/// whose [`version`](crate::Vast::version) is 3.0, e.g. one converted from a
+/// [`v3::Vast`](crate::v3::Vast).
+///
+/// The diagnostics are returned in document order.
+pub fn validate(vast: &crate::Vast) -> Vec<Diagnostic> {
+    let v3 = vast.vast_version().is_ok_and(|version| version == crate::VastVersion::V3_0);
+    let mut validator = Validator { v3, diagnostics: Vec::new() };
+    validator.vast(vast);
+    validator.diagnostics
+}
+
+impl crate::Vast<'_> {
+    /// Shorthand for [`validate`].
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        validate(self)
+    }
+}
+
+struct Validator {
+    // A VAST 3.0 document converted into the 4.x model, which has none of the elements added in
+    // VAST 4.0.
+    v3: bool,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Validator {
+    fn error(&mut self, rule: &'static str, section: &'static str, path: &str, message: &str) {
+        self.push(Severity::Error, rule, section, path, message);
+    }
+
+    fn warning(&mut self, rule: &'static str, section: &'static str, path: &str, message: &str) {
+        self.push(Severity::Warning, rule, section, path, message);
+    }
+
+    fn push(
+        &mut self,
+        severity: Severity,
+        rule: &'static str,
+        section: &'static str,
+        path: &str,
+        message: &str,
+    ) {
+        self.diagnostics.push(Diagnostic {
+            severity,
+            rule,
+            section,
+            path: path.to_owned(),
+            message: message.to_owned(),
+        });
+    }
+
+    fn vast(&mut self, vast: &crate::Vast) {
+        if vast.ads.is_empty() && vast.errors.is_empty() {
+            self.warning(
+                "vast-no-ad-error",
+                "3.2",
+                "VAST",
+                "a response without <Ad> should include an <Error> URI",
+            );
+        }
+        for (i, ad) in vast.ads.iter().enumerate() {
+            self.ad(ad, &format!("Ad[{i}]"));
+        }
+    }
+
+    fn ad(&mut self, ad: &crate::Ad, path: &str) {
+        match (&ad.in_line, &ad.wrapper) {
+            (Some(_), Some(_)) => self.error(
+                "ad-content",
+                "3.3",
+                path,
+                "<Ad> must contain either <InLine> or <Wrapper>, not both",
+            ),
+            (None, None) => {
+                self.error("ad-content", "3.3", path, "<Ad> must contain <InLine> or <Wrapper>")
+            }
+            _ => {}
+        }
+        if let Some(ref in_line) = ad.in_line {
+            self.in_line(in_line, &format!("{path}/InLine"));
+        }
+        if let Some(ref wrapper) = ad.wrapper {
+            self.wrapper(wrapper, &format!("{path}/Wrapper"));
+        }
+    }
+
+    fn in_line(&mut self, in_line: &crate::InLine, path: &str) {
+        self.ad_system(&in_line.ad_system, path);
+        self.impressions(&in_line.impressions, path, "3.4");
+        match in_line.ad_serving_id {
+            None if self.v3 => {}
+            None => self.error("inline-ad-serving-id", "3.4", path, "<AdServingId> is required"),
+            Some(ref id) if id.trim().is_empty() => {
+                self.error("inline-ad-serving-id", "3.4", path, "<AdServingId> must not be empty")
+            }
+            Some(_) => {}
+        }
+        if in_line.ad_title.trim().is_empty() {
+            self.warning("inline-ad-title", "3.4", path, "<AdTitle> should not be empty");
+        }
+        if let Some(ref ad_verifications) = in_line.ad_verifications {
+            self.ad_verifications(ad_verifications, &format!("{path}/AdVerifications"));
+        }
+
+        let path = format!("{path}/Creatives");
+        if in_line.creatives.creatives.is_empty() {
+            self.error("inline-creatives", "3.4", &path, "<Creatives> must contain a <Creative>");
+        }
+        for (i, creative) in in_line.creatives.creatives.iter().enumerate() {
+            self.creative(creative, &format!("{path}/Creative[{i}]"), true);
+        }
+    }
+
+    fn wrapper(&mut self, wrapper: &crate::Wrapper, path: &str) {
+        self.ad_system(&wrapper.ad_system, path);
+        self.impressions(&wrapper.impressions, path, "3.19");
+        if wrapper.vast_ad_tag_uri.trim().is_empty() {
+            self.error("wrapper-ad-tag-uri", "3.19", path, "<VASTAdTagURI> must not be empty");
+        }
+        if let Some(ref ad_verifications) = wrapper.ad_verifications {
+            self.ad_verifications(ad_verifications, &format!("{path}/AdVerifications"));
+        }
+        if let Some(ref creatives) = wrapper.creatives {
+            for (i, creative) in creatives.creatives.iter().enumerate() {
+                self.creative(creative, &format!("{path}/Creatives/Creative[{i}]"), false);
+            }
+        }
+    }
+
+    fn ad_system(&mut self, ad_system: &crate::AdSystem, path: &str) {
+        if ad_system.name.trim().is_empty() {
+            self.warning("ad-system", "3.4", &format!("{path}/AdSystem"), "should not be empty");
+        }
+    }
+
+    fn impressions(
+        &mut self,
+        impressions: &[crate::Impression],
+        path: &str,
+        section: &'static str,
+    ) {
+        if impressions.is_empty() {
+            self.error("impression", section, path, "at least one <Impression> is required");
+        }
+        for (i, impression) in impressions.iter().enumerate() {
+            if impression.uri.trim().is_empty() {
+                self.error(
+                    "impression-uri",
+                    section,
+                    &format!("{path}/Impression[{i}]"),
+                    "the URI must not be empty",
+                );
+            }
+        }
+    }
+
+    fn ad_verifications(&mut self, ad_verifications: &crate::AdVerifications, path: &str) {
+        for (i, verification) in ad_verifications.verifications.iter().enumerate() {
+            if verification.javascript_resources.is_empty()
+                && verification.executable_resources.is_empty()
+            {
+                self.warning(
+                    "verification-resource",
+                    "3.16",
+                    &format!("{path}/Verification[{i}]"),
+                    "<Verification> should contain a <JavaScriptResource> or an \
+                     <ExecutableResource>",
+                );
+            }
+        }
+    }
+
+    fn creative(&mut self, creative: &crate::Creative, path: &str, in_line: bool) {
+        let count = [
+            creative.linear.is_some(),
+            creative.non_linear_ads.is_some(),
+            creative.companion_ads.is_some(),
+        ]
+        .into_iter()
+        .filter(|&b| b)
+        .count();
+        if in_line && count == 0 {
+            self.error(
+                "creative-content",
+                "3.7",
+                path,
+                "<Creative> must contain <Linear>, <NonLinearAds> or <CompanionAds>",
+            );
+        }
+        if count > 1 {
+            self.warning(
+                "creative-content",
+                "3.7",
+                path,
+                "<Creative> should contain only one of <Linear>, <NonLinearAds> or <CompanionAds>",
+            );
+        }
+        if in_line && !self.v3 && creative.universal_ad_id.is_empty() {
+            self.error("creative-universal-ad-id", "3.7", path, "<UniversalAdId> is required");
+        }
+
+        if let Some(ref linear) = creative.linear {
+            self.linear(linear, &format!("{path}/Linear"), in_line);
+        }
+        if let Some(ref non_linear_ads) = creative.non_linear_ads {
+            self.non_linear_ads(non_linear_ads, &format!("{path}/NonLinearAds"), in_line);
+        }
+        if let Some(ref companion_ads) = creative.companion_ads {
+            self.companion_ads(companion_ads, &format!("{path}/CompanionAds"), in_line);
+        }
+    }
+
+    fn linear(&mut self, linear: &crate::Linear, path: &str, in_line: bool) {
+        if in_line {
+            if linear.duration.is_none() {
+                self.error("linear-duration", "3.8", path, "<Duration> is required");
+            }
+            match linear.media_files {
+                Some(ref media_files) => {
+                    self.media_files(media_files, &format!("{path}/MediaFiles"))
+                }
+                None => self.error("linear-media-files", "3.8", path, "<MediaFiles> is required"),
+            }
+        }
+        if let Some(ref tracking_events) = linear.tracking_events {
+            self.tracking_events(tracking_events, &format!("{path}/TrackingEvents"), true);
+        }
+    }
+
+    fn media_files(&mut self, media_files: &crate::MediaFiles, path: &str) {
+        if media_files.media_files.is_empty() {
+            self.error("media-file", "3.9", path, "at least one <MediaFile> is required");
+        }
+        for (i, media_file) in media_files.media_files.iter().enumerate() {
+            if media_file.uri.trim().is_empty() {
+                self.error(
+                    "media-file-uri",
+                    "3.9",
+                    &format!("{path}/MediaFile[{i}]"),
+                    "the URI must not be empty",
+                );
+            }
+            if media_file.codecs().is_err() {
+                self.warning(
+                    "media-file-codec",
+                    "3.9",
+                    &format!("{path}/MediaFile[{i}]"),
+                    "the codec should be a list of RFC 6381 codecs",
+                );
+            }
+        }
+    }
+
+    fn non_linear_ads(&mut self, non_linear_ads: &crate::NonLinearAds, path: &str, in_line: bool) {
+        if let Some(ref tracking_events) = non_linear_ads.tracking_events {
+            self.tracking_events(tracking_events, &format!("{path}/TrackingEvents"), false);
+        }
+        if !in_line {
+            return;
+        }
+        for (i, non_linear) in non_linear_ads.non_linears.iter().enumerate() {
+            if non_linear.static_resources.is_empty()
+                && non_linear.iframe_resources.is_empty()
+                && non_linear.html_resources.is_empty()
+            {
+                self.error(
+                    "non-linear-resource",
+                    "3.12",
+                    &format!("{path}/NonLinear[{i}]"),
+                    "<NonLinear> must contain a <StaticResource>, <IFrameResource> or \
+                     <HTMLResource>",
+                );
+            }
+        }
+    }
+
+    fn companion_ads(&mut self, companion_ads: &crate::CompanionAds, path: &str, in_line: bool) {
+        for (i, companion) in companion_ads.companions.iter().enumerate() {
+            let path = format!("{path}/Companion[{i}]");
+            if in_line
+                && companion.static_resources.is_empty()
+                && companion.iframe_resources.is_empty()
+                && companion.html_resources.is_empty()
+            {
+                self.error(
+                    "companion-resource",
+                    "3.13",
+                    &path,
+                    "<Companion> must contain a <StaticResource>, <IFrameResource> or \
+                     <HTMLResource>",
+                );
+            }
+            for (i, tracking) in companion.companion_click_trackings.iter().enumerate() {
+                if tracking.id.is_none() && !self.v3 {
+                    self.error(
+                        "companion-click-tracking-id",
+                        "3.13",
+                        &format!("{path}/CompanionClickTracking[{i}]"),
+                        "<CompanionClickTracking> requires an id",
+                    );
+                }
+            }
+            if let Some(ref tracking_events) = companion.tracking_events {
+                self.tracking_events(tracking_events, &format!("{path}/TrackingEvents"), false);
+            }
+        }
+    }
+
+    fn tracking_events(
+        &mut self,
+        tracking_events: &crate::TrackingEvents,
+        path: &str,
+        linear: bool,
+    ) {
+        for (i, tracking) in tracking_events.trackings.iter().enumerate() {
+            let path = format!("{path}/Tracking[{i}]");
+            if tracking.event == crate::TrackingEvent::Progress && tracking.offset.is_none() {
+                self.error("tracking-offset", "3.14", &path, "a progress event requires an offset");
+            }
+            if tracking.offset.is_some() && !linear {
+                self.warning(
+                    "tracking-offset",
+                    "3.14",
+                    &path,
+                    "offset is only available when <Linear> is the parent",
+                );
+            }
+            if tracking.uri.trim().is_empty() {
+                self.error("tracking-uri", "3.14", &path, "the URI must not be empty");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_validate() {
+    const VAST: &str = r#"<VAST version="4.2">
+      <Ad id="both">
+        <InLine>
+          <AdSystem>iabtechlab</AdSystem>
+          <AdServingId> </AdServingId>
+          <AdTitle>title</AdTitle>
+          <Creatives>
+            <Creative>
+              <UniversalAdId idRegistry="Ad-ID">8465</UniversalAdId>
+              <Linear>
+                <TrackingEvents>
+                  <Tracking event="progress"><![CDATA[https://example.com/progress]]></Tracking>
+                </TrackingEvents>
+              </Linear>
+            </Creative>
+            <Creative>
+              <UniversalAdId idRegistry="Ad-ID">8466</UniversalAdId>
+              <CompanionAds>
+                <Companion width="300" height="250">
+                  <CompanionClickTracking><![CDATA[https://example.com/click]]></CompanionClickTracking>
+                </Companion>
+              </CompanionAds>
+            </Creative>
+          </Creatives>
+        </InLine>
+        <Wrapper>
+          <AdSystem>iabtechlab</AdSystem>
+          <Impression><![CDATA[https://example.com/impression]]></Impression>
+          <VASTAdTagURI><![CDATA[https://example.com/vast]]></VASTAdTagURI>
+        </Wrapper>
+      </Ad>
+    </VAST>"#;
+
+    let vast = crate::from_str::<crate::Vast>(VAST).unwrap();
+    let diagnostics =
+        vast.validate().into_iter().map(|d| (d.severity, d.rule, d.path)).collect::<Vec<_>>();
+    assert_eq!(
+        diagnostics,
+        [
+            (Severity::Error, "ad-content", "Ad[0]".into()),
+            (Severity::Error, "impression", "Ad[0]/InLine".into()),
+            (Severity::Error, "inline-ad-serving-id", "Ad[0]/InLine".into()),
+            (
+                Severity::Error,
+                "linear-duration",
+                "Ad[0]/InLine/Creatives/Creative[0]/Linear".into()
+            ),
+            (
+                Severity::Error,
+                "linear-media-files",
+                "Ad[0]/InLine/Creatives/Creative[0]/Linear".into()
+            ),
+            (
+                Severity::Error,
+                "tracking-offset",
+                "Ad[0]/InLine/Creatives/Creative[0]/Linear/TrackingEvents/Tracking[0]".into()
+            ),
+            (
+                Severity::Error,
+                "companion-resource",
+                "Ad[0]/InLine/Creatives/Creative[1]/CompanionAds/Companion[0]".into()
+            ),
+            (
+                Severity::Error,
+                "companion-click-tracking-id",
+                "Ad[0]/InLine/Creatives/Creative[1]/CompanionAds/Companion[0]/\
+                 CompanionClickTracking[0]"
+                    .into()
+            ),
+        ]
+    );
+
+    let vast = crate::from_str::<crate::Vast>(include_str!(
+        "../tests/data/v4_2/Inline_Linear_Tag-test.xml"
+    ))
+    .unwrap();
+    assert_eq!(validate(&vast), []);
+
+    let mut vast = vast;
+    let linear = vast.ads[0].in_line.as_mut().unwrap().creatives.creatives[0].linear.as_mut();
+    let media_files = linear.unwrap().media_files.as_mut().unwrap();
+    media_files.media_files[0].codec = Some("avc1.42E01E,".into());
+    let diagnostics =
+        vast.validate().into_iter().map(|d| (d.severity, d.rule, d.path)).collect::<Vec<_>>();
+    assert_eq!(
+        diagnostics,
+        [(
+            Severity::Warning,
+            "media-file-codec",
+            "Ad[0]/InLine/Creatives/Creative[0]/Linear/MediaFiles/MediaFile[0]".into()
+        )]
+    );
+
+    vast.ads[0].in_line.as_mut().unwrap().ad_serving_id = None;
+    let diagnostics = vast.validate();
+    assert_eq!(diagnostics[0].rule, "inline-ad-serving-id");
+    assert_eq!(diagnostics[0].message, "<AdServingId> is required");
+
+    // the elements added in VAST 4.0 are not required from a VAST 3.0 document
+    let vast = crate::from_str::<crate::v3::Vast>(include_str!(
+        "../tests/data/v3_0/Inline_Linear_Tag-test.xml"
+    ))
+    .unwrap();
+    assert_eq!(crate::Vast::from(vast).validate(), []);
+}
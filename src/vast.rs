@@ -1,3 +1,6 @@
+/// The VAST namespace.
+pub const NAMESPACE: &str = "http://www.iab.com/VAST";
+
 /// IAB VAST (Video Ad Serving Template).
 ///
 /// ```text
@@ -12,6 +15,7 @@
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "VAST", strict(unknown_element))]
 pub struct Vast<'a> {
     /// A float (number with decimal) to indicate the VAST version being used.
@@ -29,6 +33,104 @@ pub struct Vast<'a> {
     pub errors: Vec<std::borrow::Cow<'a, str>>,
 }
 
+impl Vast<'_> {
+    /// Parses the [`version`](Vast::version) attribute.
+    pub fn vast_version(&self) -> Result<VastVersion, crate::VastParseError> {
+        self.version.parse()
+    }
+}
+
+/// The VAST versions this crate can read.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub enum VastVersion {
+    /// VAST 3.0, read through the [`v3`](crate::v3) module.
+    V3_0,
+    V4_0,
+    V4_1,
+    V4_2,
+}
+
+impl std::str::FromStr for VastVersion {
+    type Err = crate::VastParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "3" | "3.0" => Self::V3_0,
+            "4" | "4.0" => Self::V4_0,
+            "4.1" => Self::V4_1,
+            "4.2" => Self::V4_2,
+            _ => {
+                return Err(crate::VastParseError::new(format!(
+                    "vast version parsing error: '{s}'"
+                )));
+            }
+        })
+    }
+}
+
+impl std::fmt::Display for VastVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::V3_0 => write!(f, "3.0"),
+            Self::V4_0 => write!(f, "4.0"),
+            Self::V4_1 => write!(f, "4.1"),
+            Self::V4_2 => write!(f, "4.2"),
+        }
+    }
+}
+
+// Reads the `version` attribute of the root element.
+pub(crate) fn read_version(s: &str) -> hard_xml::XmlResult<VastVersion> {
+    let mut reader = hard_xml::XmlReader::new(s);
+    reader.read_till_element_start("VAST")?;
+    let mut version = None;
+    while let Some((key, value)) = reader.find_attribute()? {
+        if key == "version" {
+            version = Some(value);
+        }
+    }
+    let version = version.ok_or_else(|| hard_xml::XmlError::MissingField {
+        name: "Vast".into(),
+        field: "version".into(),
+    })?;
+    version.parse().map_err(|e: crate::VastParseError| hard_xml::XmlError::FromStr(e.into()))
+}
+
+/// A VAST document of any version this crate can read.
+#[derive(PartialEq, Clone, Debug)]
+pub enum AnyVast<'a> {
+    V3(crate::v3::Vast<'a>),
+    V4(Vast<'a>),
+}
+
+impl<'a> AnyVast<'a> {
+    /// Parses `s` with the model matching the `version` attribute of its root element.
+    pub fn parse(s: &'a str) -> crate::Result<Self> {
+        let version = read_version(s)
+            .map_err(|e| crate::Error::locate(s, e, |s| read_version(s).map(drop)))?;
+        match version {
+            VastVersion::V3_0 => crate::from_str(s).map(Self::V3),
+            _ => crate::from_str(s).map(Self::V4),
+        }
+    }
+
+    /// Returns the version of the document.
+    pub fn vast_version(&self) -> Result<VastVersion, crate::VastParseError> {
+        match self {
+            Self::V3(vast) => vast.version.parse(),
+            Self::V4(vast) => vast.vast_version(),
+        }
+    }
+
+    /// Converts the document into the VAST 4.x model.
+    pub fn into_vast(self) -> Vast<'a> {
+        match self {
+            Self::V3(vast) => vast.into(),
+            Self::V4(vast) => vast,
+        }
+    }
+}
+
 crate::declare_test!(
     test_vast_errors,
     Vast,
@@ -39,3 +141,21 @@ crate::declare_test!(
         ..Default::default()
     }
 );
+
+#[cfg(test)]
+#[test]
+fn test_any_vast() {
+    let vast =
+        AnyVast::parse(include_str!("../tests/data/v3_0/Inline_Linear_Tag-test.xml")).unwrap();
+    assert!(matches!(vast, AnyVast::V3(_)));
+    assert_eq!(vast.vast_version().unwrap(), VastVersion::V3_0);
+    assert_eq!(vast.into_vast().ads.len(), 1);
+
+    let vast =
+        AnyVast::parse(include_str!("../tests/data/v4_2/Inline_Linear_Tag-test.xml")).unwrap();
+    assert!(matches!(vast, AnyVast::V4(_)));
+    assert_eq!(vast.vast_version().unwrap(), VastVersion::V4_2);
+
+    assert!(AnyVast::parse(r#"<VAST version="2.0"></VAST>"#).is_err());
+    assert!(AnyVast::parse(r#"<VAST></VAST>"#).is_err());
+}
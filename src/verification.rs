@@ -9,6 +9,7 @@
 /// </xs:complexType>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "AdVerifications", strict(unknown_attribute, unknown_element))]
 pub struct AdVerifications<'a> {
     /// The container for zero or more [`<Verification>`](Verification) elements.
@@ -31,6 +32,7 @@ pub struct AdVerifications<'a> {
 /// </xs:complexType>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "Verification", strict(unknown_attribute, unknown_element))]
 pub struct Verification<'a> {
     // https://github.com/InteractiveAdvertisingBureau/vast/blob/f28dbb4768744062fcb638a1859364cdafb3a449/vast_4.2.xsd#L1136
@@ -68,6 +70,7 @@ pub struct Verification<'a> {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "JavaScriptResource", strict(unknown_attribute, unknown_element))]
 pub struct JavaScriptResource<'a> {
     // https://github.com/InteractiveAdvertisingBureau/vast/blob/f28dbb4768744062fcb638a1859364cdafb3a449/vast_4.2.xsd#L1123
@@ -101,6 +104,7 @@ pub struct JavaScriptResource<'a> {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "ExecutableResource", strict(unknown_attribute, unknown_element))]
 pub struct ExecutableResource<'a> {
     /// The name of the API framework used to execute the AdVerification code.
@@ -130,6 +134,7 @@ pub struct ExecutableResource<'a> {
 /// </xs:complexType>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "TrackingEvents", strict(unknown_attribute, unknown_element))]
 pub struct VerificationTrackingEvents<'a> {
     /// The container for zero or more [`<Tracking>`](VerificationTracking) elements.
@@ -152,6 +157,7 @@ pub struct VerificationTrackingEvents<'a> {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "Tracking", strict(unknown_attribute, unknown_element))]
 pub struct VerificationTracking<'a> {
     /// A string that defines the event being tracked. Accepted values are listed in section
@@ -13,6 +13,7 @@
 /// </xs:complexType>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "ViewableImpression", strict(unknown_attribute, unknown_element))]
 pub struct ViewableImpression<'a> {
     /// An ad server id for the impression. Impression resources of the same id should be requested
@@ -0,0 +1,552 @@
+//! An implementation of [`VMAP 1.0`].
+//!
+//! [`VMAP 1.0`]: https://www.iab.com/wp-content/uploads/2015/06/VMAP.pdf
+
+/// The VMAP namespace, to be used with the `vmap` prefix.
+pub const NAMESPACE: &str = "http://www.iab.net/videosuite/vmap";
+
+/// The root element of a VMAP document, describing the ad breaks of a content.
+///
+/// ```text
+/// <xs:element name="VMAP">
+///   <xs:complexType>
+///     <xs:sequence>
+///       <xs:element name="AdBreak" minOccurs="0" maxOccurs="unbounded" type="vmap:AdBreakType">
+///       <xs:element name="Extensions" minOccurs="0" maxOccurs="1" type="vmap:ExtensionsType">
+///     </xs:sequence>
+///     <xs:attribute name="version" type="xs:string" use="required">
+///   </xs:complexType>
+/// </xs:element>
+/// ```
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "vmap:VMAP", strict(unknown_element))]
+pub struct Vmap<'a> {
+    /// The namespace bound to the `vmap` prefix, see [`NAMESPACE`].
+    #[xml(attr = "xmlns:vmap", default)]
+    pub xmlns: Option<std::borrow::Cow<'a, str>>,
+    /// The version of the VMAP specification, must be "1.0".
+    #[xml(attr = "version")]
+    pub version: std::borrow::Cow<'a, str>,
+
+    /// The container for zero or more [`<vmap:AdBreak>`](AdBreak) elements.
+    #[xml(child = "vmap:AdBreak", default)]
+    pub ad_breaks: Vec<AdBreak<'a>>,
+    /// The container for zero or one [`<vmap:Extensions>`](Extensions) element.
+    #[xml(child = "vmap:Extensions", default)]
+    pub extensions: Option<Extensions>,
+}
+
+/// A single ad break, which may contain one or more ads.
+///
+/// ```text
+/// <xs:complexType name="AdBreakType">
+///   <xs:sequence>
+///     <xs:element name="AdSource" minOccurs="0" maxOccurs="1" type="vmap:AdSourceType">
+///     <xs:element name="TrackingEvents" minOccurs="0" maxOccurs="1" type="vmap:TrackingEventsType">
+///     <xs:element name="Extensions" minOccurs="0" maxOccurs="1" type="vmap:ExtensionsType">
+///   </xs:sequence>
+///   <xs:attribute name="timeOffset" type="xs:string" use="required">
+///   <xs:attribute name="breakType" type="xs:string" use="required">
+///   <xs:attribute name="breakId" type="xs:string" use="optional">
+///   <xs:attribute name="repeatAfter" type="xs:string" use="optional">
+/// </xs:complexType>
+/// ```
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "vmap:AdBreak", strict(unknown_attribute, unknown_element))]
+pub struct AdBreak<'a> {
+    /// The timing of the break.
+    #[xml(attr = "timeOffset")]
+    pub time_offset: TimeOffset,
+    /// The types of ads allowed in the break.
+    #[xml(attr = "breakType")]
+    pub break_type: BreakTypes,
+    /// An optional identifier for the break.
+    #[xml(attr = "breakId", default)]
+    pub break_id: Option<std::borrow::Cow<'a, str>>,
+    /// Repeat the break at the given interval, e.g. a mid-roll every 10 minutes.
+    #[xml(attr = "repeatAfter", default)]
+    pub repeat_after: Option<crate::Duration>,
+
+    /// The container for zero or one [`<vmap:AdSource>`](AdSource) element.
+    #[xml(child = "vmap:AdSource", default)]
+    pub ad_source: Option<AdSource<'a>>,
+    /// The container for zero or one [`<vmap:TrackingEvents>`](TrackingEvents) element.
+    #[xml(child = "vmap:TrackingEvents", default)]
+    pub tracking_events: Option<TrackingEvents<'a>>,
+    /// The container for zero or one [`<vmap:Extensions>`](Extensions) element.
+    #[xml(child = "vmap:Extensions", default)]
+    pub extensions: Option<Extensions>,
+}
+
+/// The ad response for a break, either embedded or referenced by URI.
+///
+/// ```text
+/// <xs:complexType name="AdSourceType">
+///   <xs:choice>
+///     <xs:element name="VASTAdData" type="vmap:VASTAdDataType">
+///     <xs:element name="CustomAdData" type="vmap:CustomAdDataType">
+///     <xs:element name="AdTagURI" type="vmap:AdTagURIType">
+///   </xs:choice>
+///   <xs:attribute name="id" type="xs:string" use="optional">
+///   <xs:attribute name="allowMultipleAds" type="xs:boolean" use="optional">
+///   <xs:attribute name="followRedirects" type="xs:boolean" use="optional">
+/// </xs:complexType>
+/// ```
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "vmap:AdSource", strict(unknown_attribute, unknown_element))]
+pub struct AdSource<'a> {
+    /// An identifier for the ad source.
+    #[xml(attr = "id", default)]
+    pub id: Option<std::borrow::Cow<'a, str>>,
+    /// Whether the ad response may contain multiple ads (an ad pod).
+    #[xml(attr = "allowMultipleAds", default)]
+    pub allow_multiple_ads: Option<bool>,
+    /// Whether the player should follow wrappers/redirects in the ad response.
+    #[xml(attr = "followRedirects", default)]
+    pub follow_redirects: Option<bool>,
+
+    /// The container for zero or one [`<vmap:VASTAdData>`](VastAdData) element.
+    #[xml(child = "vmap:VASTAdData", default)]
+    pub vast_ad_data: Option<VastAdData<'a>>,
+    /// The container for zero or one [`<vmap:CustomAdData>`](CustomAdData) element.
+    #[xml(child = "vmap:CustomAdData", default)]
+    pub custom_ad_data: Option<CustomAdData>,
+    /// The container for zero or one [`<vmap:AdTagURI>`](AdTagUri) element.
+    #[xml(child = "vmap:AdTagURI", default)]
+    pub ad_tag_uri: Option<AdTagUri<'a>>,
+}
+
+/// A VAST document embedded in the VMAP response.
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "vmap:VASTAdData", strict(unknown_attribute, unknown_element))]
+pub struct VastAdData<'a> {
+    /// The embedded [`<VAST>`](crate::Vast) element.
+    #[xml(child = "VAST")]
+    pub vast: crate::Vast<'a>,
+}
+
+/// A URI to the ad response of the break.
+///
+/// ```text
+/// <xs:complexType name="AdTagURIType">
+///   <xs:simpleContent>
+///     <xs:extension base="xs:anyURI">
+///       <xs:attribute name="templateType" type="xs:string" use="required">
+///     </xs:extension>
+///   </xs:simpleContent>
+/// </xs:complexType>
+/// ```
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "vmap:AdTagURI", strict(unknown_attribute, unknown_element))]
+pub struct AdTagUri<'a> {
+    /// The format of the ad response, e.g. "vast3" or "vast4".
+    #[xml(attr = "templateType")]
+    pub template_type: std::borrow::Cow<'a, str>,
+
+    /// A URI to the ad response.
+    #[xml(text, cdata)]
+    pub uri: std::borrow::Cow<'a, str>,
+}
+
+/// An ad response in a format other than VAST, embedded in the VMAP response.
+///
+/// ```text
+/// <xs:complexType name="CustomAdDataType">
+///   <xs:sequence>
+///     <xs:any minOccurs="0" maxOccurs="unbounded" processContents="skip" />
+///   </xs:sequence>
+///   <xs:attribute name="templateType" type="xs:string" use="required">
+/// </xs:complexType>
+/// ```
+#[derive(Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustomAdData {
+    /// The format of the ad response.
+    pub template_type: String,
+
+    /// Custom XML object.
+    pub xml: String,
+}
+
+impl hard_xml::XmlWrite for CustomAdData {
+    fn to_writer<W: std::io::Write>(
+        &self,
+        writer: &mut hard_xml::XmlWriter<W>,
+    ) -> hard_xml::XmlResult<()> {
+        writer.write_element_start("vmap:CustomAdData")?;
+        writer.write_attribute("templateType", &self.template_type)?;
+        writer.write_element_end_open()?;
+        write!(writer.inner, "{}", self.xml)?;
+        writer.write_element_end_close("vmap:CustomAdData")?;
+        Ok(())
+    }
+}
+
+impl<'a> hard_xml::XmlRead<'a> for CustomAdData {
+    fn from_reader(reader: &mut hard_xml::XmlReader<'a>) -> hard_xml::XmlResult<Self> {
+        reader.read_till_element_start("vmap:CustomAdData")?;
+
+        let mut template_type = None;
+        while let Some((name, value)) = reader.find_attribute()? {
+            match name {
+                "templateType" => template_type = Some(value.into_owned()),
+                _ => {
+                    return Err(hard_xml::XmlError::UnknownField {
+                        name: "CustomAdData".into(),
+                        field: name.into(),
+                    });
+                }
+            }
+        }
+        let template_type = template_type.ok_or_else(|| hard_xml::XmlError::MissingField {
+            name: "CustomAdData".into(),
+            field: "template_type".into(),
+        })?;
+
+        Ok(Self { template_type, xml: read_raw(reader, "CustomAdData")? })
+    }
+}
+
+/// The container for zero or more [`<vmap:Tracking>`](Tracking) elements.
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "vmap:TrackingEvents", strict(unknown_attribute, unknown_element))]
+pub struct TrackingEvents<'a> {
+    /// The container for zero or more [`<vmap:Tracking>`](Tracking) elements.
+    #[xml(child = "vmap:Tracking", default)]
+    pub trackings: Vec<Tracking<'a>>,
+}
+
+/// A URI to track an event of the ad break.
+///
+/// ```text
+/// <xs:complexType name="TrackingType">
+///   <xs:simpleContent>
+///     <xs:extension base="xs:anyURI">
+///       <xs:attribute name="event" use="required">
+///     </xs:extension>
+///   </xs:simpleContent>
+/// </xs:complexType>
+/// ```
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "vmap:Tracking", strict(unknown_attribute, unknown_element))]
+pub struct Tracking<'a> {
+    /// The event being tracked.
+    #[xml(attr = "event")]
+    pub event: TrackingEvent,
+
+    /// A URI to the tracking resource for the event.
+    #[xml(text, cdata)]
+    pub uri: std::borrow::Cow<'a, str>,
+}
+
+/// The events of an ad break.
+///
+/// ```text
+/// <xs:simpleType>
+///   <xs:restriction base="xs:token">
+///     <xs:enumeration value="breakStart" />
+///     <xs:enumeration value="breakEnd" />
+///     <xs:enumeration value="error" />
+///   </xs:restriction>
+/// </xs:simpleType>
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum TrackingEvent {
+    /// The ad break started.
+    BreakStart,
+    /// The ad break ended.
+    BreakEnd,
+    /// An error occurred with the ad break. The `[ERRORCODE]` macro is supported.
+    Error,
+}
+
+impl std::str::FromStr for TrackingEvent {
+    type Err = crate::VastParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "breakStart" => Self::BreakStart,
+            "breakEnd" => Self::BreakEnd,
+            "error" => Self::Error,
+            _ => {
+                return Err(crate::VastParseError::new(format!(
+                    "vmap tracking event parsing error: '{s}'"
+                )));
+            }
+        })
+    }
+}
+
+impl std::fmt::Display for TrackingEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BreakStart => write!(f, "breakStart"),
+            Self::BreakEnd => write!(f, "breakEnd"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// The container for zero or more [`<vmap:Extension>`](Extension) elements.
+#[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml(tag = "vmap:Extensions", strict(unknown_attribute, unknown_element))]
+pub struct Extensions {
+    /// The container for zero or more [`<vmap:Extension>`](Extension) elements.
+    #[xml(child = "vmap:Extension", default)]
+    pub extensions: Vec<Extension>,
+}
+
+/// A custom extension, as defined by the ad server.
+#[derive(Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Extension {
+    /// Identifies the extension.
+    pub extension_type: Option<String>,
+
+    /// Custom XML object.
+    pub xml: String,
+}
+
+impl hard_xml::XmlWrite for Extension {
+    fn to_writer<W: std::io::Write>(
+        &self,
+        writer: &mut hard_xml::XmlWriter<W>,
+    ) -> hard_xml::XmlResult<()> {
+        writer.write_element_start("vmap:Extension")?;
+        if let Some(ref attr) = self.extension_type {
+            writer.write_attribute("type", attr)?;
+        }
+        writer.write_element_end_open()?;
+        write!(writer.inner, "{}", self.xml)?;
+        writer.write_element_end_close("vmap:Extension")?;
+        Ok(())
+    }
+}
+
+impl<'a> hard_xml::XmlRead<'a> for Extension {
+    fn from_reader(reader: &mut hard_xml::XmlReader<'a>) -> hard_xml::XmlResult<Self> {
+        reader.read_till_element_start("vmap:Extension")?;
+
+        let mut extension_type = None;
+        while let Some((name, value)) = reader.find_attribute()? {
+            match name {
+                "type" => extension_type = Some(value.into_owned()),
+                _ => {
+                    return Err(hard_xml::XmlError::UnknownField {
+                        name: "Extension".into(),
+                        field: name.into(),
+                    });
+                }
+            }
+        }
+
+        Ok(Self { extension_type, xml: read_raw(reader, "Extension")? })
+    }
+}
+
+// Reads the content of the current element as raw XML, after its attributes.
+fn read_raw(reader: &mut hard_xml::XmlReader, local_name: &str) -> hard_xml::XmlResult<String> {
+    use hard_xml::xmlparser::{ElementEnd, Token};
+
+    match reader.next() {
+        Some(Ok(Token::ElementEnd { end: ElementEnd::Open, .. })) => {}
+        Some(Ok(Token::ElementEnd { end: ElementEnd::Empty, .. })) => return Ok(String::new()),
+        Some(t) => return Err(hard_xml::XmlError::UnexpectedToken { token: format!("{:?}", t?) }),
+        None => return Err(hard_xml::XmlError::UnexpectedEof),
+    }
+
+    let mut depth = 0;
+    let mut xml = String::new();
+
+    while let Some(t) = reader.next() {
+        match t? {
+            Token::Declaration { span, .. }
+            | Token::ProcessingInstruction { span, .. }
+            | Token::Comment { span, .. }
+            | Token::DtdStart { span, .. }
+            | Token::EmptyDtd { span, .. }
+            | Token::EntityDeclaration { span, .. }
+            | Token::DtdEnd { span }
+            | Token::ElementStart { span, .. }
+            | Token::Cdata { span, .. } => xml.push_str(span.as_str()),
+            Token::Attribute { span, .. } => {
+                xml.push(' ');
+                xml.push_str(span.as_str())
+            }
+            Token::Text { text } => xml.push_str(text.as_str()),
+            Token::ElementEnd { end, span } => {
+                match end {
+                    ElementEnd::Open => depth += 1,
+                    ElementEnd::Close(_, name) => {
+                        if depth == 0 && name.as_str() == local_name {
+                            return Ok(xml);
+                        }
+                        depth -= 1;
+                    }
+                    ElementEnd::Empty => {}
+                };
+                xml.push_str(span.as_str())
+            }
+        }
+    }
+
+    Err(hard_xml::XmlError::UnexpectedEof)
+}
+
+/// The timing of an [`AdBreak`].
+#[derive(PartialEq, Clone, Debug)]
+pub enum TimeOffset {
+    /// `start`: before the content (pre-roll).
+    Start,
+    /// `end`: after the content (post-roll).
+    End,
+    /// `#n`: the n-th position, e.g. the n-th cue point of the content.
+    Position(u32),
+    /// `HH:MM:SS.mmm` or `n%`: a time in the content.
+    Offset(crate::Offset),
+}
+
+impl std::str::FromStr for TimeOffset {
+    type Err = crate::VastParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "start" => Self::Start,
+            "end" => Self::End,
+            trimmed => match trimmed.strip_prefix('#') {
+                Some(n) => Self::Position(n.parse().map_err(|_| {
+                    crate::VastParseError::new(format!("time offset parsing error: '{s}'"))
+                })?),
+                None => Self::Offset(trimmed.parse()?),
+            },
+        })
+    }
+}
+
+impl std::fmt::Display for TimeOffset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Start => write!(f, "start"),
+            Self::End => write!(f, "end"),
+            Self::Position(n) => write!(f, "#{n}"),
+            Self::Offset(offset) => write!(f, "{offset}"),
+        }
+    }
+}
+
+/// The type of ads allowed in an [`AdBreak`].
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum BreakType {
+    Linear,
+    NonLinear,
+    Display,
+}
+
+impl std::str::FromStr for BreakType {
+    type Err = crate::VastParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "linear" => Self::Linear,
+            "nonlinear" => Self::NonLinear,
+            "display" => Self::Display,
+            _ => {
+                return Err(crate::VastParseError::new(format!("break type parsing error: '{s}'")));
+            }
+        })
+    }
+}
+
+impl std::fmt::Display for BreakType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Linear => write!(f, "linear"),
+            Self::NonLinear => write!(f, "nonlinear"),
+            Self::Display => write!(f, "display"),
+        }
+    }
+}
+
+/// The comma separated list of [`BreakType`]s of an [`AdBreak`], e.g. `linear,nonlinear`.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct BreakTypes(pub Vec<BreakType>);
+
+impl std::str::FromStr for BreakTypes {
+    type Err = crate::VastParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',').map(|t| t.trim().parse()).collect::<Result<_, _>>().map(Self)
+    }
+}
+
+impl std::fmt::Display for BreakTypes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, t) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{t}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_time_offset() {
+    for (s, offset) in [
+        ("start", TimeOffset::Start),
+        ("end", TimeOffset::End),
+        ("#2", TimeOffset::Position(2)),
+        (
+            "00:10:00",
+            TimeOffset::Offset(crate::Offset::Duration(crate::Duration::new(0, 10, 0, 0))),
+        ),
+        (
+            "00:00:15.500",
+            TimeOffset::Offset(crate::Offset::Duration(crate::Duration::new(0, 0, 15, 500))),
+        ),
+        ("50%", TimeOffset::Offset(crate::Offset::Percentage(50))),
+    ] {
+        assert_eq!(s.parse::<TimeOffset>().unwrap(), offset);
+        assert_eq!(offset.to_string(), s);
+    }
+    assert!("#".parse::<TimeOffset>().is_err());
+    assert!("middle".parse::<TimeOffset>().is_err());
+}
+
+crate::declare_test!(
+    test_ad_break,
+    AdBreak,
+    r#"<vmap:AdBreak timeOffset="end" breakType="linear,nonlinear" breakId="postroll"><vmap:AdSource id="postroll-ad-1" allowMultipleAds="false"><vmap:CustomAdData templateType="custom"><Ad id="1"/></vmap:CustomAdData></vmap:AdSource><vmap:TrackingEvents><vmap:Tracking event="breakStart"><![CDATA[https://example.com/breakStart]]></vmap:Tracking></vmap:TrackingEvents></vmap:AdBreak>"#,
+    AdBreak {
+        time_offset: TimeOffset::End,
+        break_type: BreakTypes(vec![BreakType::Linear, BreakType::NonLinear]),
+        break_id: Some("postroll".into()),
+        repeat_after: None,
+        ad_source: Some(AdSource {
+            id: Some("postroll-ad-1".into()),
+            allow_multiple_ads: Some(false),
+            custom_ad_data: Some(CustomAdData {
+                template_type: "custom".into(),
+                xml: r#"<Ad id="1"/>"#.into()
+            }),
+            ..Default::default()
+        }),
+        tracking_events: Some(TrackingEvents {
+            trackings: vec![Tracking {
+                event: TrackingEvent::BreakStart,
+                uri: "https://example.com/breakStart".into()
+            }]
+        }),
+        extensions: None,
+    }
+);
@@ -26,6 +26,7 @@
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "Wrapper", strict(unknown_attribute, unknown_element))]
 pub struct Wrapper<'a> {
     /// A Boolean value that identifies whether subsequent Wrappers after a
@@ -97,6 +98,7 @@ pub struct Wrapper<'a> {
 /// </xs:element>
 /// ```
 #[derive(hard_xml::XmlWrite, hard_xml::XmlRead, Default, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[xml(tag = "BlockedAdCategories", strict(unknown_attribute, unknown_element))]
 pub struct BlockedAdCategories<'a> {
     /// A URL for the organizational authority that produced the list being used to identify
@@ -0,0 +1,324 @@
+/// The options for writing a document, e.g. with indentation.
+///
+/// ```
+/// let vast = vast4::Vast {
+///     version: "4.2".into(),
+///     errors: vec!["https://example.com".into()],
+///     ..Default::default()
+/// };
+/// let options = vast4::WriteOptions { indent: Some(2), declaration: true, ..Default::default() };
+/// assert_eq!(
+///     options.to_string(&vast).unwrap(),
+///     r#"<?xml version="1.0" encoding="UTF-8"?>
+/// <VAST version="4.2">
+///   <Error><![CDATA[https://example.com]]></Error>
+/// </VAST>"#
+/// );
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+pub struct WriteOptions {
+    /// The number of spaces each level of nesting is indented by, with each element on its own
+    /// line and elements containing only text kept on one line. `None` writes the document on one
+    /// line, as [`to_string`](crate::to_string) does. Default value is `None`.
+    ///
+    /// The content of `<Extension>`, `<CreativeExtension>` and `<vmap:CustomAdData>` elements is
+    /// written as is.
+    pub indent: Option<usize>,
+    /// Whether to write the `<?xml version="1.0" encoding="UTF-8"?>` declaration first. Default
+    /// value is `false`.
+    pub declaration: bool,
+    /// Whether to add the [`NAMESPACE`](crate::NAMESPACE) to a `<VAST>` root element without it.
+    /// Default value is `false`.
+    pub namespace: bool,
+    /// Whether to write the text written in CDATA sections, e.g. URIs, in CDATA sections. The text
+    /// is escaped instead when `false`. Default value is `true`.
+    pub cdata: bool,
+}
+
+impl Default for WriteOptions {
+    fn default() -> Self {
+        Self { indent: None, declaration: false, namespace: false, cdata: true }
+    }
+}
+
+// elements whose content is custom XML
+const RAW: &[&[u8]] =
+    &[b"Extension", b"CreativeExtension", b"vmap:Extension", b"vmap:CustomAdData"];
+
+impl WriteOptions {
+    pub fn to_string<T: hard_xml::XmlWrite>(&self, value: &T) -> hard_xml::XmlResult<String> {
+        let mut buf = Vec::new();
+        self.to_writer(value, &mut buf)?;
+        Ok(String::from_utf8(buf)?)
+    }
+
+    /// Writes `value` to `writer`, as it is written by the model.
+    pub fn to_writer<T: hard_xml::XmlWrite, W: std::io::Write>(
+        &self,
+        value: &T,
+        writer: W,
+    ) -> hard_xml::XmlResult<()> {
+        let mut formatter = Formatter {
+            inner: writer,
+            options: self,
+            state: State::Text,
+            token: Vec::new(),
+            space: Vec::new(),
+            parents: Vec::new(),
+            raw: 0,
+            root: false,
+            written: false,
+        };
+        if self.declaration {
+            std::io::Write::write_all(
+                &mut formatter,
+                br#"<?xml version="1.0" encoding="UTF-8"?>"#,
+            )?;
+        }
+        let mut writer = hard_xml::XmlWriter::new(formatter);
+        value.to_writer(&mut writer)?;
+        writer.inner.finish()?;
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum State {
+    Text,
+    Tag { quote: Option<u8> },
+    Cdata,
+    Comment,
+    ProcessingInstruction,
+}
+
+// Rewrites the output of the model token by token, holding only the token being written.
+struct Formatter<'o, W> {
+    inner: W,
+    options: &'o WriteOptions,
+    state: State,
+    token: Vec<u8>,
+    // whitespace only text, written when it is the only content of its element
+    space: Vec<u8>,
+    // whether each open element contains elements
+    parents: Vec<bool>,
+    // the number of open elements inside of an element with custom XML
+    raw: usize,
+    root: bool,
+    written: bool,
+}
+
+impl<W: std::io::Write> Formatter<'_, W> {
+    fn push(&mut self, b: u8) -> std::io::Result<()> {
+        match self.state {
+            State::Text if b == b'<' => {
+                self.text()?;
+                self.token.push(b);
+                self.state = State::Tag { quote: None };
+            }
+            State::Text => self.token.push(b),
+            State::Tag { quote } => {
+                self.token.push(b);
+                match (quote, b) {
+                    _ if self.token == b"<![CDATA[" => self.state = State::Cdata,
+                    _ if self.token == b"<!--" => self.state = State::Comment,
+                    _ if self.token == b"<?" => self.state = State::ProcessingInstruction,
+                    (Some(q), _) if q == b => self.state = State::Tag { quote: None },
+                    (None, b'"' | b'\'') => self.state = State::Tag { quote: Some(b) },
+                    (None, b'>') => self.tag()?,
+                    _ => {}
+                }
+            }
+            State::Cdata => {
+                self.token.push(b);
+                if self.token.ends_with(b"]]>") {
+                    self.cdata()?;
+                }
+            }
+            State::Comment | State::ProcessingInstruction => {
+                self.token.push(b);
+                let end: &[u8] = if self.state == State::Comment { b"-->" } else { b"?>" };
+                if self.token.ends_with(end) {
+                    self.other()?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> std::io::Result<()> {
+        self.text()?;
+        self.inner.flush()
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<()> {
+        self.written = true;
+        self.inner.write_all(bytes)
+    }
+
+    fn newline(&mut self) -> std::io::Result<()> {
+        match self.options.indent {
+            Some(indent) if self.written => {
+                let line = format!("\n{:1$}", "", indent * self.parents.len());
+                self.write(line.as_bytes())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn text(&mut self) -> std::io::Result<()> {
+        let token = std::mem::take(&mut self.token);
+        self.state = State::Text;
+        if self.raw == 0
+            && self.options.indent.is_some()
+            && token.iter().all(u8::is_ascii_whitespace)
+        {
+            self.space = token;
+            Ok(())
+        } else {
+            self.write(&token)
+        }
+    }
+
+    fn tag(&mut self) -> std::io::Result<()> {
+        let token = std::mem::take(&mut self.token);
+        let space = std::mem::take(&mut self.space);
+        self.state = State::Text;
+        let end = token.starts_with(b"</");
+        let empty = token.ends_with(b"/>");
+
+        if self.raw > 0 {
+            if end {
+                self.raw -= 1;
+            } else if !empty {
+                self.raw += 1;
+            }
+            // the end of the element with custom XML follows its content
+            if end && self.raw == 0 {
+                self.parents.pop();
+            }
+            return self.write(&token);
+        }
+
+        if end {
+            if self.parents.pop().unwrap_or_default() {
+                self.newline()?;
+            } else {
+                self.write(&space)?;
+            }
+            return self.write(&token);
+        }
+
+        if let Some(parent) = self.parents.last_mut() {
+            *parent = true;
+        }
+        self.newline()?;
+        let name_end = token[1..]
+            .iter()
+            .position(|&b| b.is_ascii_whitespace() || b == b'/' || b == b'>')
+            .map_or(token.len(), |i| i + 1);
+        let name = &token[1..name_end];
+
+        let root = !std::mem::replace(&mut self.root, true);
+        if root && self.options.namespace && name == b"VAST" && !contains(&token, b" xmlns=") {
+            let close = token.len() - if empty { 2 } else { 1 };
+            self.write(&token[..close])?;
+            self.write(format!(r#" xmlns="{}""#, crate::NAMESPACE).as_bytes())?;
+            self.write(&token[close..])?;
+        } else {
+            self.write(&token)?;
+        }
+
+        if !empty {
+            self.parents.push(false);
+            if RAW.contains(&name) {
+                self.raw = 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn cdata(&mut self) -> std::io::Result<()> {
+        let token = std::mem::take(&mut self.token);
+        self.state = State::Text;
+        if self.raw > 0 || self.options.cdata {
+            return self.write(&token);
+        }
+        let text = &token[b"<![CDATA[".len()..token.len() - b"]]>".len()];
+        let text = std::str::from_utf8(text)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        self.write(hard_xml::utils::xml_escape(text).as_bytes())
+    }
+
+    fn other(&mut self) -> std::io::Result<()> {
+        let token = std::mem::take(&mut self.token);
+        self.state = State::Text;
+        if self.raw == 0 {
+            if let Some(parent) = self.parents.last_mut() {
+                *parent = true;
+            }
+            self.newline()?;
+        }
+        self.write(&token)
+    }
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|w| w == needle)
+}
+
+impl<W: std::io::Write> std::io::Write for Formatter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        for &b in buf {
+            self.push(b)?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_write_options() {
+    let xml = include_str!("../tests/data/v4_2/Inline_Simple.xml");
+    let vast = crate::from_str::<crate::Vast>(xml).unwrap();
+
+    let options = WriteOptions::default();
+    assert_eq!(options.to_string(&vast).unwrap(), crate::to_string(&vast).unwrap());
+
+    let options =
+        WriteOptions { indent: Some(2), declaration: true, namespace: true, cdata: false };
+    let output = options.to_string(&vast).unwrap();
+    assert_eq!(crate::from_str::<crate::Vast>(&output).unwrap(), vast);
+    let lines: Vec<_> = output.lines().take(6).collect();
+    assert_eq!(
+        lines,
+        [
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+            r#"<VAST version="4.2" xmlns="http://www.iab.com/VAST">"#,
+            r#"  <Ad id="20001">"#,
+            r#"    <InLine>"#,
+            r#"      <AdSystem version="1">iabtechlab</AdSystem>"#,
+            r#"      <Impression id="Impression-ID">https://example.com/track/impression</Impression>"#,
+        ]
+    );
+
+    let mut buf = Vec::new();
+    WriteOptions { indent: Some(4), ..Default::default() }.to_writer(&vast, &mut buf).unwrap();
+    assert!(String::from_utf8(buf).unwrap().contains("\n            <Creatives>\n"));
+
+    // custom XML is written as is
+    let extensions = crate::Extensions {
+        extensions: vec![crate::Extension {
+            mime_type: Some("foo".to_owned()),
+            xml: "<Foo><Bar a=\"&gt;\"/>\n  </Foo>".to_owned(),
+        }],
+    };
+    let options = WriteOptions { indent: Some(1), ..Default::default() };
+    assert_eq!(
+        options.to_string(&extensions).unwrap(),
+        "<Extensions>\n <Extension type=\"foo\"><Foo><Bar a=\"&gt;\"/>\n  </Foo></Extension>\n</Extensions>"
+    );
+}
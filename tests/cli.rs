@@ -0,0 +1,121 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const LINEAR: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/v4_2/Inline_Linear_Tag-test.xml");
+
+fn vast4(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_vast4"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn cli_lint() {
+    let data = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data");
+    for file in [LINEAR, &format!("{data}/v3_0/Inline_Linear_Tag-test.xml")] {
+        let output = vast4(&["lint", file], "");
+        assert_eq!(output.status.code(), Some(0), "{file}");
+        assert_eq!(stdout(&output), "");
+    }
+
+    // a VAST 3.0 document is checked once converted
+    let xml = std::fs::read_to_string(format!("{data}/v3_0/Inline_Linear_Tag-test.xml")).unwrap();
+    let output = vast4(&["lint"], &xml.replace("<Duration>00:00:16</Duration>", ""));
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout(&output).starts_with("-: error[linear-duration] "));
+
+    let output = vast4(&["lint", "-"], r#"<VAST version="4.2"><Ad>"#);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout(&output).starts_with("-:1:"));
+
+    let output = vast4(&["lint", "missing.xml"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(std::str::from_utf8(&output.stderr).unwrap().starts_with("missing.xml: "));
+
+    let output = vast4(&["unknown"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(std::str::from_utf8(&output.stderr).unwrap().starts_with("Usage: vast4"));
+}
+
+#[test]
+fn cli_fmt_and_json() {
+    let xml = std::fs::read_to_string(LINEAR).unwrap();
+
+    let output = vast4(&["fmt", LINEAR], "");
+    assert!(output.status.success());
+    let indented = stdout(&output);
+    assert!(indented.contains("\n  <Ad id=\"20001\""));
+    pretty_assertions::assert_eq!(
+        vast4::from_str::<vast4::Vast>(indented).unwrap(),
+        vast4::from_str::<vast4::Vast>(&xml).unwrap()
+    );
+
+    let output = vast4(&["json"], &xml);
+    assert!(output.status.success());
+    let json: vast4::Vast = serde_json::from_str(stdout(&output)).unwrap();
+    pretty_assertions::assert_eq!(json, vast4::from_str::<vast4::Vast>(&xml).unwrap());
+}
+
+#[test]
+fn cli_summary() {
+    let output = vast4(&["summary", LINEAR], "");
+    assert!(output.status.success());
+    let lines: Vec<_> = stdout(&output).lines().collect();
+    assert_eq!(lines[0], format!("{LINEAR}: VAST 4.2, 1 ad(s)"));
+    assert_eq!(
+        lines[1..4],
+        [
+            r#"Ad[0] id=20001 InLine "iabtechlab video ad" (iabtechlab)"#,
+            "  impressions: 1",
+            "  Creative[0] id=5480 Linear duration=00:00:16",
+        ]
+    );
+    assert!(lines[4].starts_with("    MediaFile 1280x720 video/mp4 2000kbps progressive https://"));
+    assert_eq!(
+        lines.last().unwrap(),
+        &"    tracking: start=1 progress=1 firstQuartile=1 midpoint=1 thirdQuartile=1 complete=1"
+    );
+}
+
+#[test]
+fn cli_urls() {
+    let output = vast4(&["urls", LINEAR], "");
+    assert!(output.status.success());
+    let lines: Vec<_> = stdout(&output).lines().collect();
+    assert_eq!(
+        lines[..2],
+        ["error\thttps://example.com/error", "impression\thttps://example.com/track/impression"]
+    );
+    assert_eq!(lines.last().unwrap(), &"click-through\thttps://iabtechlab.com");
+    assert!(lines.iter().all(|line| line.split('\t').count() == 2));
+}
+
+#[test]
+fn cli_broken_pipe() {
+    // far more output than a pipe buffers, so the writes fail once the reader is gone
+    let mut args = vec!["urls"];
+    args.extend(std::iter::repeat_n(LINEAR, 2000));
+    let mut child = Command::new(env!("CARGO_BIN_EXE_vast4"))
+        .args(&args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    drop(child.stdout.take());
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(std::str::from_utf8(&output.stderr).unwrap(), "");
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "serde")]
+
+fn round_trip<'a, T>(xml: &'a str)
+where
+    T: hard_xml::XmlRead<'a> + hard_xml::XmlWrite + serde::Serialize + serde::de::DeserializeOwned,
+    T: PartialEq + std::fmt::Debug,
+{
+    let obj = vast4::from_str::<T>(xml).unwrap();
+    let json = serde_json::to_string(&obj).unwrap();
+    let de = serde_json::from_str::<T>(&json).unwrap();
+    pretty_assertions::assert_eq!(de, obj);
+    pretty_assertions::assert_eq!(vast4::to_string(&de).unwrap(), vast4::to_string(&obj).unwrap());
+}
+
+#[test]
+fn json_round_trip() {
+    let data = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data");
+    let mut count = 0;
+    for ver in ["v3_0", "v4_2", "vmap_1_0"] {
+        for file in std::fs::read_dir(format!("{data}/{ver}")).unwrap() {
+            let xml = std::fs::read_to_string(file.unwrap().path()).unwrap();
+            match ver {
+                "v3_0" => round_trip::<vast4::v3::Vast>(&xml),
+                "vmap_1_0" => round_trip::<vast4::vmap::Vmap>(&xml),
+                _ => round_trip::<vast4::Vast>(&xml),
+            }
+            count += 1;
+        }
+    }
+    assert!(count > 0);
+}
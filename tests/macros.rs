@@ -1,6 +1,6 @@
 #[macro_export]
 macro_rules! __declare_test {
-    ($ver:expr, $name:expr, $obj:expr) => {
+    ($ty:ty, $ver:expr, $name:expr, $obj:expr) => {
         #[test]
         fn read_and_write() {
             pub use std::borrow::Cow;
@@ -8,7 +8,7 @@ macro_rules! __declare_test {
             pub use vast4::*;
 
             let xml = include_str!(concat!("../data/", $ver, "/", $name, ".xml"));
-            let vast = vast4::from_str::<vast4::Vast>(xml).unwrap();
+            let vast = vast4::from_str::<$ty>(xml).unwrap();
             pretty_assertions::assert_eq!($obj, vast);
             let xml = vast4::to_string(&vast).unwrap();
             std::fs::create_dir_all(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/output/", $ver))
@@ -17,8 +17,12 @@ macro_rules! __declare_test {
                 concat!(env!("CARGO_MANIFEST_DIR"), "/tests/output/", $ver, "/", $name, ".xml");
             std::fs::write(file, xml).unwrap();
             let file = std::fs::read_to_string(file).unwrap();
-            let from_file = vast4::from_str::<vast4::Vast>(&file).unwrap();
+            let from_file = vast4::from_str::<$ty>(&file).unwrap();
             pretty_assertions::assert_eq!(vast, from_file);
+
+            let options = vast4::WriteOptions { indent: Some(2), ..Default::default() };
+            let indented = options.to_string(&vast).unwrap();
+            pretty_assertions::assert_eq!(vast, vast4::from_str::<$ty>(&indented).unwrap());
         }
     };
 }
@@ -26,6 +30,20 @@ macro_rules! __declare_test {
 #[macro_export]
 macro_rules! declare_test_v4_2 {
     ($name:expr, $obj:expr) => {
-        __declare_test!("v4_2", $name, $obj);
+        __declare_test!(vast4::Vast, "v4_2", $name, $obj);
+    };
+}
+
+#[macro_export]
+macro_rules! declare_test_v3_0 {
+    ($name:expr, $obj:expr) => {
+        __declare_test!(vast4::v3::Vast, "v3_0", $name, $obj);
+    };
+}
+
+#[macro_export]
+macro_rules! declare_test_vmap_1_0 {
+    ($name:expr, $obj:expr) => {
+        __declare_test!(vast4::vmap::Vmap, "vmap_1_0", $name, $obj);
     };
 }
@@ -1,4 +1,9 @@
 #[macro_use]
 mod macros;
 
+mod cli;
+mod json;
+mod preserve;
+mod v3_0;
 mod v4_2;
+mod vmap_1_0;
@@ -0,0 +1,60 @@
+use vast4::preserve::Document;
+
+// Removes the whitespace inside tags, which is not kept.
+fn strip_tag_space(xml: &str) -> String {
+    let mut in_tag = false;
+    xml.chars()
+        .filter(|&c| {
+            match c {
+                '<' => in_tag = true,
+                '>' => in_tag = false,
+                _ => {}
+            }
+            !(in_tag && c.is_whitespace())
+        })
+        .collect()
+}
+
+#[test]
+fn round_trip() {
+    let data = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data");
+    let mut count = 0;
+    for dir in std::fs::read_dir(data).unwrap() {
+        for file in std::fs::read_dir(dir.unwrap().path()).unwrap() {
+            let path = file.unwrap().path();
+            let xml = std::fs::read_to_string(&path).unwrap();
+            let document = Document::parse(&xml).unwrap();
+            let output = document.to_string();
+            pretty_assertions::assert_eq!(
+                strip_tag_space(&output),
+                strip_tag_space(&xml),
+                "{}",
+                path.display()
+            );
+            assert_eq!(Document::parse(&output).unwrap(), document);
+            count += 1;
+        }
+    }
+    assert!(count > 0);
+}
+
+#[test]
+fn replace_unchanged() {
+    let data = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/v4_2");
+    let mut count = 0;
+    for file in std::fs::read_dir(data).unwrap() {
+        let xml = std::fs::read_to_string(file.unwrap().path()).unwrap();
+        let mut document = Document::parse(&xml).unwrap();
+        let before = document.to_string();
+        for i in 0.. {
+            let path = format!("Ad[0]/InLine/Creatives/Creative[{i}]");
+            let Some(creative) = document.root_mut().find_mut(&path) else { break };
+            let mut buf = String::new();
+            let value = creative.as_struct::<vast4::Creative>(&mut buf).unwrap();
+            creative.replace_with(&value).unwrap();
+            count += 1;
+        }
+        pretty_assertions::assert_eq!(document.to_string(), before);
+    }
+    assert!(count > 0);
+}
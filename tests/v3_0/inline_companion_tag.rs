@@ -0,0 +1,99 @@
+crate::declare_test_v3_0!(
+    "Inline_Companion_Tag-test",
+    v3::Vast {
+        version: Cow::Borrowed("3.0"),
+        ads: vec![v3::Ad {
+            id: Some(Cow::Borrowed("20011")),
+            sequence: Some(1),
+            in_line: Some(v3::InLine {
+                ad_system: AdSystem {
+                    version: Some(Cow::Borrowed("4.0")),
+                    name: Cow::Borrowed("iabtechlab"),
+                },
+                ad_title: Cow::Borrowed("iabtechlab video ad"),
+                errors: vec![Cow::Borrowed("http://example.com/error")],
+                impressions: vec![Impression {
+                    id: Some(Cow::Borrowed("Impression-ID")),
+                    uri: Cow::Borrowed("http://example.com/track/impression"),
+                }],
+                creatives: v3::Creatives {
+                    creatives: vec![
+                        v3::Creative {
+                            id: Some(Cow::Borrowed("5480")),
+                            sequence: Some(1),
+                            linear: Some(v3::Linear {
+                                duration: Some(Duration::new(0, 0, 16, 0)),
+                                media_files: Some(MediaFiles {
+                                    media_files: vec![MediaFile {
+                                        id: Some(Cow::Borrowed("5241")),
+                                        delivery: DeliveryProtocol::Progressive,
+                                        mime_type: Cow::Borrowed("video/mp4"),
+                                        bitrate: Some(500),
+                                        width: 400,
+                                        height: 300,
+                                        scalable: Some(true),
+                                        maintain_aspect_ratio: Some(true),
+                                        uri: Cow::Borrowed(
+                                            "https://iab-publicfiles.s3.amazonaws.com/vast/VAST-4.0-Short-Intro.mp4"
+                                        ),
+                                        ..Default::default()
+                                    }],
+                                    ..Default::default()
+                                }),
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        },
+                        v3::Creative {
+                            id: Some(Cow::Borrowed("5481")),
+                            sequence: Some(1),
+                            companion_ads: Some(v3::CompanionAds {
+                                required: Some(CompanionRequirement::Any),
+                                companions: vec![v3::Companion {
+                                    id: Some(Cow::Borrowed("1232")),
+                                    width: 100,
+                                    height: 150,
+                                    asset_width: Some(250),
+                                    asset_height: Some(200),
+                                    expanded_width: Some(350),
+                                    expanded_height: Some(250),
+                                    api_framework: Some(Cow::Borrowed("VPAID")),
+                                    ad_slot_id: Some(Cow::Borrowed("3214")),
+                                    static_resources: vec![StaticResource {
+                                        creative_type: Cow::Borrowed("image/png"),
+                                        uri: Cow::Borrowed(
+                                            "https://www.iab.com/wp-content/uploads/2014/09/iab-tech-lab-6-644x290.png"
+                                        ),
+                                    }],
+                                    tracking_events: Some(v3::TrackingEvents {
+                                        trackings: vec![v3::Tracking {
+                                            event: v3::TrackingEvent::CreativeView,
+                                            offset: None,
+                                            uri: Cow::Borrowed(
+                                                "http://example.com/tracking/creativeView"
+                                            ),
+                                        }],
+                                    }),
+                                    companion_click_through: Some(Cow::Borrowed(
+                                        "https://iabtechlab.com"
+                                    )),
+                                    companion_click_trackings: vec![v3::CompanionClickTracking {
+                                        id: None,
+                                        uri: Cow::Borrowed(
+                                            "http://example.com/tracking/companionClick"
+                                        ),
+                                    }],
+                                    ..Default::default()
+                                }],
+                            }),
+                            ..Default::default()
+                        },
+                    ],
+                },
+                ..Default::default()
+            }),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+);
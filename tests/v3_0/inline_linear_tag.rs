@@ -0,0 +1,134 @@
+crate::declare_test_v3_0!(
+    "Inline_Linear_Tag-test",
+    v3::Vast {
+        version: Cow::Borrowed("3.0"),
+        ads: vec![v3::Ad {
+            id: Some(Cow::Borrowed("20001")),
+            sequence: Some(1),
+            in_line: Some(v3::InLine {
+                ad_system: AdSystem {
+                    version: Some(Cow::Borrowed("4.0")),
+                    name: Cow::Borrowed("iabtechlab"),
+                },
+                ad_title: Cow::Borrowed("iabtechlab video ad"),
+                pricing: Some(Pricing {
+                    model: PriceModel::Cpm,
+                    currency: Cow::Borrowed("USD"),
+                    price: 25.0,
+                }),
+                errors: vec![Cow::Borrowed("http://example.com/error")],
+                impressions: vec![Impression {
+                    id: Some(Cow::Borrowed("Impression-ID")),
+                    uri: Cow::Borrowed("http://example.com/track/impression"),
+                }],
+                creatives: v3::Creatives {
+                    creatives: vec![v3::Creative {
+                        id: Some(Cow::Borrowed("5480")),
+                        sequence: Some(1),
+                        ad_id: Some(Cow::Borrowed("2447226")),
+                        linear: Some(v3::Linear {
+                            skipoffset: Some(Duration::new(0, 0, 5, 0)),
+                            duration: Some(Duration::new(0, 0, 16, 0)),
+                            tracking_events: Some(v3::TrackingEvents {
+                                trackings: vec![
+                                    v3::Tracking {
+                                        event: v3::TrackingEvent::CreativeView,
+                                        offset: None,
+                                        uri: Cow::Borrowed(
+                                            "http://example.com/tracking/creativeView"
+                                        ),
+                                    },
+                                    v3::Tracking {
+                                        event: v3::TrackingEvent::Start,
+                                        offset: None,
+                                        uri: Cow::Borrowed("http://example.com/tracking/start"),
+                                    },
+                                    v3::Tracking {
+                                        event: v3::TrackingEvent::FirstQuartile,
+                                        offset: None,
+                                        uri: Cow::Borrowed(
+                                            "http://example.com/tracking/firstQuartile"
+                                        ),
+                                    },
+                                    v3::Tracking {
+                                        event: v3::TrackingEvent::MidPoint,
+                                        offset: None,
+                                        uri: Cow::Borrowed("http://example.com/tracking/midpoint"),
+                                    },
+                                    v3::Tracking {
+                                        event: v3::TrackingEvent::ThirdQuartile,
+                                        offset: None,
+                                        uri: Cow::Borrowed(
+                                            "http://example.com/tracking/thirdQuartile"
+                                        ),
+                                    },
+                                    v3::Tracking {
+                                        event: v3::TrackingEvent::Complete,
+                                        offset: None,
+                                        uri: Cow::Borrowed("http://example.com/tracking/complete"),
+                                    },
+                                    v3::Tracking {
+                                        event: v3::TrackingEvent::Fullscreen,
+                                        offset: None,
+                                        uri: Cow::Borrowed(
+                                            "http://example.com/tracking/fullscreen"
+                                        ),
+                                    },
+                                    v3::Tracking {
+                                        event: v3::TrackingEvent::ExitFullscreen,
+                                        offset: None,
+                                        uri: Cow::Borrowed(
+                                            "http://example.com/tracking/exitFullscreen"
+                                        ),
+                                    },
+                                    v3::Tracking {
+                                        event: v3::TrackingEvent::Progress,
+                                        offset: Some(Offset::Duration(Duration::new(0, 0, 10, 0))),
+                                        uri: Cow::Borrowed(
+                                            "http://example.com/tracking/progress-10"
+                                        ),
+                                    },
+                                ],
+                            }),
+                            video_clicks: Some(VideoClicks {
+                                click_through: Some(ClickThrough {
+                                    id: Some(Cow::Borrowed("blog")),
+                                    uri: Cow::Borrowed("https://iabtechlab.com"),
+                                }),
+                                click_trackings: vec![ClickTracking {
+                                    id: None,
+                                    uri: Cow::Borrowed(
+                                        "http://example.com/tracking/clickTracking"
+                                    ),
+                                }],
+                                ..Default::default()
+                            }),
+                            media_files: Some(MediaFiles {
+                                media_files: vec![MediaFile {
+                                    id: Some(Cow::Borrowed("5241")),
+                                    delivery: DeliveryProtocol::Progressive,
+                                    mime_type: Cow::Borrowed("video/mp4"),
+                                    bitrate: Some(500),
+                                    width: 400,
+                                    height: 300,
+                                    scalable: Some(true),
+                                    maintain_aspect_ratio: Some(true),
+                                    uri: Cow::Borrowed(
+                                        "https://iab-publicfiles.s3.amazonaws.com/vast/VAST-4.0-Short-Intro.mp4"
+                                    ),
+                                    ..Default::default()
+                                }],
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }],
+                },
+                ..Default::default()
+            }),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+);
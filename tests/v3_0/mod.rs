@@ -0,0 +1,3 @@
+mod inline_companion_tag;
+mod inline_linear_tag;
+mod wrapper_tag;
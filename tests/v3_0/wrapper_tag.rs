@@ -0,0 +1,82 @@
+crate::declare_test_v3_0!(
+    "Wrapper_Tag-test",
+    v3::Vast {
+        version: Cow::Borrowed("3.0"),
+        ads: vec![v3::Ad {
+            id: Some(Cow::Borrowed("20011")),
+            wrapper: Some(v3::Wrapper {
+                follow_additional_wrappers: Some(false),
+                allow_multiple_ads: Some(true),
+                fallback_on_no_ad: Some(false),
+                ad_system: AdSystem {
+                    version: Some(Cow::Borrowed("4.0")),
+                    name: Cow::Borrowed("iabtechlab"),
+                },
+                vast_ad_tag_uri: Cow::Borrowed(
+                    "https://raw.githubusercontent.com/InteractiveAdvertisingBureau/VAST_Samples/master/VAST%203.0%20Samples/Inline_Companion_Tag-test.xml"
+                ),
+                errors: vec![Cow::Borrowed("http://example.com/error")],
+                impressions: vec![Impression {
+                    id: Some(Cow::Borrowed("Impression-ID")),
+                    uri: Cow::Borrowed("http://example.com/track/impression"),
+                }],
+                creatives: Some(v3::Creatives {
+                    creatives: vec![
+                        v3::Creative {
+                            id: Some(Cow::Borrowed("5480")),
+                            sequence: Some(1),
+                            companion_ads: Some(v3::CompanionAds {
+                                companions: vec![v3::Companion {
+                                    id: Some(Cow::Borrowed("1232")),
+                                    width: 100,
+                                    height: 150,
+                                    static_resources: vec![StaticResource {
+                                        creative_type: Cow::Borrowed("image/png"),
+                                        uri: Cow::Borrowed(
+                                            "https://www.iab.com/wp-content/uploads/2014/09/iab-tech-lab-6-644x290.png"
+                                        ),
+                                    }],
+                                    companion_click_through: Some(Cow::Borrowed(
+                                        "https://iabtechlab.com"
+                                    )),
+                                    ..Default::default()
+                                }],
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        },
+                        v3::Creative {
+                            id: Some(Cow::Borrowed("5481")),
+                            sequence: Some(1),
+                            linear: Some(v3::Linear {
+                                tracking_events: Some(v3::TrackingEvents {
+                                    trackings: vec![
+                                        v3::Tracking {
+                                            event: v3::TrackingEvent::Expand,
+                                            offset: None,
+                                            uri: Cow::Borrowed(
+                                                "http://example.com/tracking/expand"
+                                            ),
+                                        },
+                                        v3::Tracking {
+                                            event: v3::TrackingEvent::AcceptInvitationLinear,
+                                            offset: None,
+                                            uri: Cow::Borrowed(
+                                                "http://example.com/tracking/acceptInvitationLinear"
+                                            ),
+                                        },
+                                    ],
+                                }),
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        },
+                    ],
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+);
@@ -15,7 +15,7 @@ crate::declare_test_v4_2!(
                     id: Some(Cow::Borrowed("Impression-ID")),
                     uri: Cow::Borrowed("https://example.com/track/impression"),
                 },],
-                ad_serving_id: Cow::Borrowed("a532d16d-4d7f-4440-bd29-2ec0e693fc82"),
+                ad_serving_id: Some(Cow::Borrowed("a532d16d-4d7f-4440-bd29-2ec0e693fc82")),
                 categories: vec![
                     Category {
                         authority: Cow::Borrowed("https://www.iabtechlab.com/categoryauthority"),
@@ -14,7 +14,7 @@ crate::declare_test_v4_2!(
                     id: Some(Cow::Borrowed("Impression-ID")),
                     uri: Cow::Borrowed("https://example.com/track/impression"),
                 },],
-                ad_serving_id: Cow::Borrowed("a532d16d-4d7f-4440-bd29-2ec0e693fc80"),
+                ad_serving_id: Some(Cow::Borrowed("a532d16d-4d7f-4440-bd29-2ec0e693fc80")),
                 description: Some(Cow::Borrowed(
                     "This is sample companion ad tag with Linear ad tag. This tag while showing video ad on the player, will show a companion ad beside the player where it can be fitted. At most 3 companion ads can be placed. Modify accordingly to see your own content. "
                 )),
@@ -0,0 +1 @@
+mod vmap_sample;
@@ -0,0 +1,117 @@
+crate::declare_test_vmap_1_0!(
+    "VMAP_Sample",
+    vmap::Vmap {
+        xmlns: Some(Cow::Borrowed("http://www.iab.net/videosuite/vmap")),
+        version: Cow::Borrowed("1.0"),
+        ad_breaks: vec![
+            vmap::AdBreak {
+                time_offset: vmap::TimeOffset::Start,
+                break_type: vmap::BreakTypes(vec![vmap::BreakType::Linear]),
+                break_id: Some(Cow::Borrowed("preroll")),
+                repeat_after: None,
+                ad_source: Some(vmap::AdSource {
+                    id: Some(Cow::Borrowed("preroll-ad-1")),
+                    allow_multiple_ads: Some(false),
+                    follow_redirects: Some(true),
+                    ad_tag_uri: Some(vmap::AdTagUri {
+                        template_type: Cow::Borrowed("vast4"),
+                        uri: Cow::Borrowed("https://example.com/vast?break=preroll"),
+                    }),
+                    ..Default::default()
+                }),
+                tracking_events: Some(vmap::TrackingEvents {
+                    trackings: vec![
+                        vmap::Tracking {
+                            event: vmap::TrackingEvent::BreakStart,
+                            uri: Cow::Borrowed(
+                                "https://example.com/track/breakStart?break=preroll"
+                            ),
+                        },
+                        vmap::Tracking {
+                            event: vmap::TrackingEvent::Error,
+                            uri: Cow::Borrowed("https://example.com/track/error?code=[ERRORCODE]"),
+                        },
+                    ],
+                }),
+                extensions: None,
+            },
+            vmap::AdBreak {
+                time_offset: vmap::TimeOffset::Offset(Offset::Duration(Duration::new(0, 10, 0, 0))),
+                break_type: vmap::BreakTypes(vec![
+                    vmap::BreakType::Linear,
+                    vmap::BreakType::NonLinear
+                ]),
+                break_id: Some(Cow::Borrowed("midroll")),
+                repeat_after: Some(Duration::new(0, 10, 0, 0)),
+                ad_source: Some(vmap::AdSource {
+                    id: Some(Cow::Borrowed("midroll-ad-1")),
+                    allow_multiple_ads: Some(true),
+                    vast_ad_data: Some(vmap::VastAdData {
+                        vast: Vast {
+                            version: Cow::Borrowed("4.2"),
+                            ads: vec![Ad {
+                                id: Some(Cow::Borrowed("20001")),
+                                wrapper: Some(Wrapper {
+                                    ad_system: AdSystem {
+                                        version: Some(Cow::Borrowed("1")),
+                                        name: Cow::Borrowed("iabtechlab"),
+                                    },
+                                    impressions: vec![Impression {
+                                        id: Some(Cow::Borrowed("Impression-ID")),
+                                        uri: Cow::Borrowed("https://example.com/track/impression"),
+                                    }],
+                                    vast_ad_tag_uri: Cow::Borrowed(
+                                        "https://example.com/vast?break=midroll"
+                                    ),
+                                    ..Default::default()
+                                }),
+                                ..Default::default()
+                            }],
+                            errors: vec![],
+                        },
+                    }),
+                    ..Default::default()
+                }),
+                tracking_events: None,
+                extensions: None,
+            },
+            vmap::AdBreak {
+                time_offset: vmap::TimeOffset::Position(1),
+                break_type: vmap::BreakTypes(vec![vmap::BreakType::Display]),
+                break_id: None,
+                repeat_after: None,
+                ad_source: Some(vmap::AdSource {
+                    custom_ad_data: Some(vmap::CustomAdData {
+                        template_type: "custom".into(),
+                        xml: r#"<Ad id="display-1"/>"#.into(),
+                    }),
+                    ..Default::default()
+                }),
+                tracking_events: None,
+                extensions: None,
+            },
+            vmap::AdBreak {
+                time_offset: vmap::TimeOffset::End,
+                break_type: vmap::BreakTypes(vec![vmap::BreakType::Linear]),
+                break_id: Some(Cow::Borrowed("postroll")),
+                repeat_after: None,
+                ad_source: Some(vmap::AdSource {
+                    id: Some(Cow::Borrowed("postroll-ad-1")),
+                    ad_tag_uri: Some(vmap::AdTagUri {
+                        template_type: Cow::Borrowed("vast3"),
+                        uri: Cow::Borrowed("https://example.com/vast?break=postroll"),
+                    }),
+                    ..Default::default()
+                }),
+                tracking_events: None,
+                extensions: Some(vmap::Extensions {
+                    extensions: vec![vmap::Extension {
+                        extension_type: Some("countdown".into()),
+                        xml: "<Label>Ad break</Label>".into(),
+                    }],
+                }),
+            },
+        ],
+        extensions: None,
+    }
+);